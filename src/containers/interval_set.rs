@@ -414,4 +414,23 @@ mod tests {
         s.remove_point(Ordered::new(1.0).unwrap());
         assert_eq!(format!("{s}"), "(0,1),");
     }
+
+    // ---- 11. 分数端点（按数值排序） ---------------------------------------------
+    #[test]
+    fn fraction_endpoints() {
+        use crate::math::fraction::Fraction;
+        let mut s = IntervalSet {
+            points: OrderedSet::new(),
+            intervals: OrderedMap::new(),
+        };
+        s.add_interval(ivl!(Fraction::new(1i64, 3), Fraction::new(1, 2), false, false));
+        s.add_interval(ivl!(Fraction::new(2i64, 5), Fraction::new(2, 3), false, true));
+        s.remove_point(Fraction::new(3i64, 7));
+        assert!(s.contains_point(&Fraction::new(1, 3)));
+        assert!(s.contains_point(&Fraction::new(3, 5)));
+        assert!(!s.contains_point(&Fraction::new(3, 7)));
+        assert!(!s.contains_point(&Fraction::new(2, 3)));
+        assert!(!s.contains_point(&Fraction::new(1, 4)));
+        assert_eq!(s.intervals.len(), 1);
+    }
 }
@@ -0,0 +1,165 @@
+use crate::math::digits::{arabic_num_to_char, char_to_arabic_num};
+
+///how digits are written as text. a digit may take several chars, so `decode` reads
+///one token off the front of the text and reports how many bytes it used
+pub trait DigitAlphabet {
+    fn encode(&self, digit: u8) -> Option<String>;
+    ///(digit, token length in bytes) for the token at the start of text. the length must be
+    ///at least one byte and end on a char boundary, readers reject anything else
+    fn decode(&self, text: &str) -> Option<(u8, usize)>;
+}
+
+///0-9a-zA-Z, and [n] for any digit past 61, as written by `Digits::cast_to_string`
+pub struct Arabic;
+impl DigitAlphabet for Arabic {
+    fn encode(&self, digit: u8) -> Option<String> {
+        Some(match arabic_num_to_char(digit) {
+            Some(c) => c.to_string(),
+            None => format!("[{digit}]"),
+        })
+    }
+    fn decode(&self, text: &str) -> Option<(u8, usize)> {
+        if let Some(rest) = text.strip_prefix('[') {
+            let end = rest.find(']')?;
+            return Some((rest[..end].parse().ok()?, end + 2));
+        }
+        let c = text.chars().next()?;
+        Some((char_to_arabic_num(c)?, c.len_utf8()))
+    }
+}
+
+///one char per digit, looked up in a table
+pub struct CharTable {
+    chars: Vec<char>,
+    ignore_case: bool,
+}
+impl CharTable {
+    ///chars[i] is the digit i
+    pub fn new(chars: &str) -> CharTable {
+        CharTable { chars: chars.chars().collect(), ignore_case: false }
+    }
+    ///also read the other case of every letter, writing stays as given
+    pub fn ignore_case(mut self) -> CharTable {
+        self.ignore_case = true;
+        self
+    }
+    ///0-9a-f, reading A-F as well
+    pub fn hex() -> CharTable {
+        CharTable::new("0123456789abcdef").ignore_case()
+    }
+    ///the Bitcoin alphabet, without 0, O, I and l
+    pub fn base58() -> CharTable {
+        CharTable::new("123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz")
+    }
+    ///RFC 4648 base64 digits, padding is not part of a number
+    pub fn base64() -> CharTable {
+        CharTable::new("ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/")
+    }
+    ///RFC 4648 url-safe base64 digits
+    pub fn base64_url() -> CharTable {
+        CharTable::new("ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_")
+    }
+}
+impl DigitAlphabet for CharTable {
+    fn encode(&self, digit: u8) -> Option<String> {
+        self.chars.get(digit as usize).map(|c| c.to_string())
+    }
+    fn decode(&self, text: &str) -> Option<(u8, usize)> {
+        let c = text.chars().next()?;
+        let position = if(self.ignore_case){
+            self.chars.iter().position(|d| d.eq_ignore_ascii_case(&c))
+        }else{
+            self.chars.iter().position(|d| *d == c)
+        };
+        Some((position? as u8, c.len_utf8()))
+    }
+}
+
+///Crockford base32: 0-9A-Z without I, L, O and U, read in any case with I/L as 1 and O as 0
+pub struct Crockford;
+const CROCKFORD: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+impl DigitAlphabet for Crockford {
+    fn encode(&self, digit: u8) -> Option<String> {
+        CROCKFORD.get(digit as usize).map(|c| (*c as char).to_string())
+    }
+    fn decode(&self, text: &str) -> Option<(u8, usize)> {
+        let c = text.chars().next()?.to_ascii_uppercase();
+        let c = match c {
+            'I' | 'L' => '1',
+            'O' => '0',
+            _ => c,
+        };
+        Some((CROCKFORD.iter().position(|d| *d as char == c)? as u8, 1))
+    }
+}
+
+///any pair of closures or fn pointers, one char per digit
+pub struct FnAlphabet<E, D>(pub E, pub D);
+impl<E: Fn(u8) -> Option<char>, D: Fn(char) -> Option<u8>> DigitAlphabet for FnAlphabet<E, D> {
+    fn encode(&self, digit: u8) -> Option<String> {
+        (self.0)(digit).map(|c| c.to_string())
+    }
+    fn decode(&self, text: &str) -> Option<(u8, usize)> {
+        let c = text.chars().next()?;
+        Some(((self.1)(c)?, c.len_utf8()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::digits::Digits;
+
+    #[test]
+    fn test_builtin_alphabets() {
+        let hex = CharTable::hex();
+        assert_eq!(Digits::from_string_with("FF", 16, &hex).unwrap().to_u64(), Some(255));
+        assert_eq!(Digits::from_u64(255, 16).to_string_with(&hex).unwrap(), "ff");
+        let base58 = CharTable::base58();
+        assert_eq!(Digits::from_u64(58, 58).to_string_with(&base58).unwrap(), "21");
+        assert!(Digits::from_string_with("0", 58, &base58).is_err());
+        assert_eq!(Digits::from_u64(63, 64).to_string_with(&CharTable::base64()).unwrap(), "/");
+        assert_eq!(Digits::from_string_with("1o", 32, &Crockford).unwrap().to_u64(), Some(32));
+        assert_eq!(Digits::from_string_with("iL", 32, &Crockford).unwrap().to_u64(), Some(33));
+        assert!(Digits::from_string_with("U", 32, &Crockford).is_err());
+        //more letters than the base allows
+        assert!(Digits::from_string_with("g", 16, &hex).is_err());
+    }
+
+    #[test]
+    fn test_tokens_and_closures() {
+        let digits = Digits::from_u64(99 * 100 + 42, 100);
+        let text = digits.cast_to_string();
+        assert_eq!(text, "[99]G");
+        assert_eq!(Digits::from_string_with(&text, 100, &Arabic).unwrap().to_u64(), Some(9942));
+        assert!(Digits::from_string_with("[99", 100, &Arabic).is_err());
+        let offset = 1;
+        let shifted = FnAlphabet(|d: u8| char::from_digit(d as u32 + offset, 10), |c: char| c.to_digit(10).map(|d| (d - offset) as u8));
+        assert_eq!(Digits::from_u64(12, 8).to_string_with(&shifted).unwrap(), "25");
+        assert_eq!(Digits::from_string_with("25", 8, &shifted).unwrap().to_u64(), Some(12));
+    }
+
+    #[test]
+    fn test_bad_token_length() {
+        struct Stuck;
+        impl DigitAlphabet for Stuck {
+            fn encode(&self, digit: u8) -> Option<String> {
+                Some(digit.to_string())
+            }
+            fn decode(&self, _text: &str) -> Option<(u8, usize)> {
+                Some((0, 0))
+            }
+        }
+        assert!(Digits::from_string_with("1", 10, &Stuck).is_err());
+        struct HalfChar;
+        impl DigitAlphabet for HalfChar {
+            fn encode(&self, digit: u8) -> Option<String> {
+                Some(digit.to_string())
+            }
+            fn decode(&self, _text: &str) -> Option<(u8, usize)> {
+                Some((1, 1))
+            }
+        }
+        assert!(Digits::from_string_with("一", 10, &HalfChar).is_err());
+    }
+}
@@ -0,0 +1,657 @@
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+use std::str::FromStr;
+use crate::math::digits::{char_to_arabic_num, Digits, DigitsError};
+use crate::math::fraction::Fraction;
+use crate::math::traits::Integer;
+
+///radix of the stored digit vector, a power of ten keeps decimal text cheap
+const BASE: u32 = 100;
+
+//---- schoolbook arithmetic on little-endian digit slices in BASE ----
+
+fn trim(mut v: Vec<u8>) -> Vec<u8> {
+    while v.last() == Some(&0) {
+        v.pop();
+    }
+    v
+}
+#[inline]
+fn digit_at(v: &[u8], i: usize) -> u32 {
+    v.get(i).map_or(0, |d| *d as u32)
+}
+fn cmp_slices(a: &[u8], b: &[u8]) -> Ordering {
+    a.len().cmp(&b.len()).then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+fn add_slices(a: &[u8], b: &[u8]) -> Vec<u8> {
+    let mut result = Vec::with_capacity(a.len().max(b.len()) + 1);
+    let mut carry = 0;
+    for i in 0..a.len().max(b.len()) {
+        let sum = digit_at(a, i) + digit_at(b, i) + carry;
+        result.push((sum % BASE) as u8);
+        carry = sum / BASE;
+    }
+    if(carry > 0){
+        result.push(carry as u8);
+    }
+    result
+}
+///a - b, requires a >= b
+fn sub_slices(a: &[u8], b: &[u8]) -> Vec<u8> {
+    let mut result = Vec::with_capacity(a.len());
+    let mut borrow = 0;
+    for i in 0..a.len() {
+        let sub = digit_at(b, i) + borrow;
+        let d = digit_at(a, i);
+        if(d >= sub){
+            result.push((d - sub) as u8);
+            borrow = 0;
+        }else{
+            result.push((d + BASE - sub) as u8);
+            borrow = 1;
+        }
+    }
+    debug_assert!(borrow == 0, "subtracting a larger number");
+    trim(result)
+}
+fn mul_small(a: &[u8], m: u32) -> Vec<u8> {
+    let mut result = Vec::with_capacity(a.len() + 5);
+    let mut carry: u64 = 0;
+    for d in a {
+        let v = *d as u64 * m as u64 + carry;
+        result.push((v % BASE as u64) as u8);
+        carry = v / BASE as u64;
+    }
+    while carry > 0 {
+        result.push((carry % BASE as u64) as u8);
+        carry /= BASE as u64;
+    }
+    trim(result)
+}
+fn mul_slices(a: &[u8], b: &[u8]) -> Vec<u8> {
+    if(a.is_empty() || b.is_empty()){
+        return Vec::new();
+    }
+    let mut acc = vec![0u64; a.len() + b.len()];
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            acc[i + j] += *x as u64 * *y as u64;
+        }
+    }
+    let mut result = Vec::with_capacity(acc.len());
+    let mut carry = 0;
+    for v in acc {
+        let v = v + carry;
+        result.push((v % BASE as u64) as u8);
+        carry = v / BASE as u64;
+    }
+    trim(result)
+}
+fn divrem_small(a: &[u8], d: u32) -> (Vec<u8>, u32) {
+    let mut quotient = vec![0u8; a.len()];
+    let mut rem: u64 = 0;
+    for i in (0..a.len()).rev() {
+        rem = rem * BASE as u64 + a[i] as u64;
+        quotient[i] = (rem / d as u64) as u8;
+        rem %= d as u64;
+    }
+    (trim(quotient), rem as u32)
+}
+///long division, every quotient digit is found by binary search on b*q <= remainder
+fn divrem_slices(a: &[u8], b: &[u8]) -> (Vec<u8>, Vec<u8>) {
+    if(b.len() == 1){
+        let (q, r) = divrem_small(a, b[0] as u32);
+        return (q, trim(vec![r as u8]));
+    }
+    let mut quotient = vec![0u8; a.len()];
+    let mut rem: Vec<u8> = Vec::new();
+    for i in (0..a.len()).rev() {
+        rem.insert(0, a[i]);
+        rem = trim(rem);
+        let (mut low, mut high) = (0, BASE - 1);
+        while low < high {
+            let mid = (low + high).div_ceil(2);
+            if(cmp_slices(&mul_small(b, mid), &rem) != Ordering::Greater){
+                low = mid;
+            }else{
+                high = mid - 1;
+            }
+        }
+        quotient[i] = low as u8;
+        rem = sub_slices(&rem, &mul_small(b, low));
+    }
+    (trim(quotient), rem)
+}
+
+///arbitrary-precision unsigned integer, stored as `Digits` in base 100 without leading zeros
+#[derive(Clone)]
+pub struct BigUint {
+    digits: Digits,
+}
+impl BigUint {
+    fn from_vec(digits: Vec<u8>) -> Self {
+        BigUint { digits: Digits::from_u8_array(trim(digits), BASE as u8).expect("digits are below the base") }
+    }
+    #[inline]
+    fn as_slice(&self) -> &[u8] {
+        self.digits.get_u8_array()
+    }
+
+    pub fn zero() -> Self {
+        Self::from_vec(Vec::new())
+    }
+    pub fn one() -> Self {
+        Self::from_vec(vec![1])
+    }
+    pub fn is_zero(&self) -> bool {
+        self.as_slice().is_empty()
+    }
+
+    pub fn from_u128(mut value: u128) -> Self {
+        let mut digits = Vec::new();
+        while value > 0 {
+            digits.push((value % BASE as u128) as u8);
+            value /= BASE as u128;
+        }
+        Self::from_vec(digits)
+    }
+    ///`None` when the value does not fit in u128
+    pub fn to_u128(&self) -> Option<u128> {
+        self.as_slice().iter().rev().try_fold(0u128, |acc, d| {
+            acc.checked_mul(BASE as u128)?.checked_add(*d as u128)
+        })
+    }
+    pub fn to_u64(&self) -> Option<u64> {
+        self.to_u128().and_then(|v| u64::try_from(v).ok())
+    }
+
+    ///the value of digits in any base
+    pub fn from_digits(digits: &Digits) -> Self {
+        let base = digits.max_digit() as u32;
+        let mut value = Vec::new();
+        for d in digits.get_u8_array().iter().rev() {
+            value = add_slices(&mul_small(&value, base), &trim(vec![*d]));
+        }
+        Self::from_vec(value)
+    }
+    ///the value as digits in `base`, zero has no digits like `Digits::from_u64(0, base)`
+    pub fn to_digits(&self, base: u8) -> Digits {
+        if(base < 2){
+            panic!("Base must be at least 2.");
+        }
+        let mut result = Vec::new();
+        let mut rest = self.as_slice().to_vec();
+        while !rest.is_empty() {
+            let (q, r) = divrem_small(&rest, base as u32);
+            result.push(r as u8);
+            rest = q;
+        }
+        Digits::from_u8_array(result, base).expect("remainders are below the base")
+    }
+    ///parse digits 0-9a-zA-Z in `base`
+    pub fn from_str_radix(text: &str, base: u8) -> Result<Self, DigitsError> {
+        if(text.is_empty()){
+            return Err(DigitsError::Empty);
+        }
+        Ok(Self::from_digits(&Digits::from_string(text, base, char_to_arabic_num)?))
+    }
+    ///digits 0-9a-zA-Z in `base`, digits past 61 are written as [n]
+    pub fn to_string_radix(&self, base: u8) -> String {
+        if(self.is_zero()){
+            return "0".to_string();
+        }
+        self.to_digits(base).cast_to_string()
+    }
+
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        if(cmp_slices(self.as_slice(), other.as_slice()) == Ordering::Less){
+            None
+        }else{
+            Some(Self::from_vec(sub_slices(self.as_slice(), other.as_slice())))
+        }
+    }
+    ///(quotient, remainder), `None` for a zero divisor
+    pub fn checked_div_rem(&self, other: &Self) -> Option<(Self, Self)> {
+        if(other.is_zero()){
+            return None;
+        }
+        let (q, r) = divrem_slices(self.as_slice(), other.as_slice());
+        Some((Self::from_vec(q), Self::from_vec(r)))
+    }
+    pub fn div_rem(&self, other: &Self) -> (Self, Self) {
+        self.checked_div_rem(other).expect("Cannot divide by zero.")
+    }
+    pub fn pow(&self, mut exp: u32) -> Self {
+        let mut result = Self::one();
+        let mut base = self.clone();
+        while exp > 0 {
+            if(exp & 1 == 1){
+                result = &result * &base;
+            }
+            exp >>= 1;
+            if(exp > 0){
+                base = &base * &base;
+            }
+        }
+        result
+    }
+    pub fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        while !b.is_zero() {
+            let r = &a % &b;
+            a = b;
+            b = r;
+        }
+        a
+    }
+}
+impl Default for BigUint {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+impl PartialEq for BigUint {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+impl Eq for BigUint {}
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_slices(self.as_slice(), other.as_slice())
+    }
+}
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Hash for BigUint {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state);
+    }
+}
+impl Display for BigUint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut text = String::new();
+        for (i, d) in self.as_slice().iter().rev().enumerate() {
+            if(i == 0){
+                text.push_str(&d.to_string());
+            }else{
+                text.push_str(&format!("{d:02}"));
+            }
+        }
+        if(text.is_empty()){
+            text.push('0');
+        }
+        f.pad_integral(true, "", &text)
+    }
+}
+impl Debug for BigUint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+impl FromStr for BigUint {
+    type Err = DigitsError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_radix(s, 10)
+    }
+}
+macro_rules! impl_biguint_from {
+    ($($t:ty),*) => {$(
+        impl From<$t> for BigUint {
+            fn from(value: $t) -> Self {
+                Self::from_u128(value as u128)
+            }
+        }
+    )*};
+}
+impl_biguint_from!(u8, u16, u32, u64, u128, usize);
+
+///arbitrary-precision signed integer as sign and magnitude, zero is never negative
+#[derive(Clone, PartialEq, Eq, Hash, Default)]
+pub struct BigInt {
+    negative: bool,
+    magnitude: BigUint,
+}
+impl BigInt {
+    pub fn new(negative: bool, magnitude: BigUint) -> Self {
+        BigInt { negative: negative && !magnitude.is_zero(), magnitude }
+    }
+    pub fn zero() -> Self {
+        Self::new(false, BigUint::zero())
+    }
+    pub fn one() -> Self {
+        Self::new(false, BigUint::one())
+    }
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_zero()
+    }
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+    pub fn magnitude(&self) -> &BigUint {
+        &self.magnitude
+    }
+    pub fn abs(&self) -> Self {
+        Self::new(false, self.magnitude.clone())
+    }
+
+    ///any primitive or user integer, through its decimal text
+    pub fn from_integer<T: Integer>(value: T) -> Self {
+        value.to_string().parse().expect("integers print as decimal")
+    }
+    ///`None` when the value does not fit in T
+    pub fn to_integer<T: Integer>(&self) -> Option<T> {
+        //horner from the top digit, subtracting for negatives so T::MIN is reachable
+        let base = T::from_i128(BASE as i128)?;
+        self.magnitude.as_slice().iter().rev().try_fold(T::ZERO, |acc, d| {
+            let d = T::from_i128(*d as i128)?;
+            let shifted = acc.checked_mul(base)?;
+            if(self.negative){ shifted.checked_sub(d) }else{ shifted.checked_add(d) }
+        })
+    }
+
+    pub fn from_str_radix(text: &str, base: u8) -> Result<Self, DigitsError> {
+        let (negative, rest) = match text.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, text.strip_prefix('+').unwrap_or(text)),
+        };
+        Ok(Self::new(negative, BigUint::from_str_radix(rest, base)?))
+    }
+    pub fn to_string_radix(&self, base: u8) -> String {
+        let sign = if(self.negative){ "-" }else{ "" };
+        format!("{sign}{}", self.magnitude.to_string_radix(base))
+    }
+
+    ///truncated division like the primitive `/` and `%`, `None` for a zero divisor
+    pub fn checked_div_rem(&self, other: &Self) -> Option<(Self, Self)> {
+        let (q, r) = self.magnitude.checked_div_rem(&other.magnitude)?;
+        Some((Self::new(self.negative != other.negative, q), Self::new(self.negative, r)))
+    }
+    pub fn div_rem(&self, other: &Self) -> (Self, Self) {
+        self.checked_div_rem(other).expect("Cannot divide by zero.")
+    }
+    pub fn pow(&self, exp: u32) -> Self {
+        Self::new(self.negative && exp % 2 == 1, self.magnitude.pow(exp))
+    }
+
+    fn add_signed(&self, other: &Self, other_negative: bool) -> Self {
+        if(self.negative == other_negative){
+            return Self::new(self.negative, &self.magnitude + &other.magnitude);
+        }
+        match self.magnitude.cmp(&other.magnitude) {
+            Ordering::Less => Self::new(other_negative, &other.magnitude - &self.magnitude),
+            _ => Self::new(self.negative, &self.magnitude - &other.magnitude),
+        }
+    }
+}
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, false) => self.magnitude.cmp(&other.magnitude),
+            (true, true) => other.magnitude.cmp(&self.magnitude),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
+    }
+}
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Display for BigInt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.pad_integral(!self.negative, "", &self.magnitude.to_string())
+    }
+}
+impl Debug for BigInt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+impl FromStr for BigInt {
+    type Err = DigitsError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_radix(s, 10)
+    }
+}
+impl From<BigUint> for BigInt {
+    fn from(value: BigUint) -> Self {
+        Self::new(false, value)
+    }
+}
+impl Neg for BigInt {
+    type Output = BigInt;
+    fn neg(self) -> BigInt {
+        Self::new(!self.negative, self.magnitude)
+    }
+}
+impl Neg for &BigInt {
+    type Output = BigInt;
+    fn neg(self) -> BigInt {
+        BigInt::new(!self.negative, self.magnitude.clone())
+    }
+}
+
+///the operator traits for owned and borrowed operands, all forwarding to the borrowed one
+macro_rules! impl_big_op {
+    ($t:ty, $op:ident, $method:ident, |$a:ident, $b:ident| $body:expr) => {
+        impl $op<&$t> for &$t {
+            type Output = $t;
+            fn $method(self, other: &$t) -> $t {
+                let ($a, $b) = (self, other);
+                $body
+            }
+        }
+        impl $op<$t> for $t {
+            type Output = $t;
+            fn $method(self, other: $t) -> $t {
+                (&self).$method(&other)
+            }
+        }
+        impl $op<&$t> for $t {
+            type Output = $t;
+            fn $method(self, other: &$t) -> $t {
+                (&self).$method(other)
+            }
+        }
+    };
+}
+impl_big_op!(BigUint, Add, add, |a, b| BigUint::from_vec(add_slices(a.as_slice(), b.as_slice())));
+impl_big_op!(BigUint, Sub, sub, |a, b| a.checked_sub(b).expect("attempt to subtract with overflow"));
+impl_big_op!(BigUint, Mul, mul, |a, b| BigUint::from_vec(mul_slices(a.as_slice(), b.as_slice())));
+impl_big_op!(BigUint, Div, div, |a, b| a.div_rem(b).0);
+impl_big_op!(BigUint, Rem, rem, |a, b| a.div_rem(b).1);
+impl_big_op!(BigInt, Add, add, |a, b| a.add_signed(b, b.negative));
+impl_big_op!(BigInt, Sub, sub, |a, b| a.add_signed(b, !b.negative));
+impl_big_op!(BigInt, Mul, mul, |a, b| BigInt::new(a.negative != b.negative, &a.magnitude * &b.magnitude));
+impl_big_op!(BigInt, Div, div, |a, b| a.div_rem(b).0);
+impl_big_op!(BigInt, Rem, rem, |a, b| a.div_rem(b).1);
+
+///exact rational with big integer parts, in lowest terms with a positive denominator.
+///the big counterpart of `Fraction`, which needs `Copy` integers
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct BigFraction {
+    p: BigInt,
+    q: BigUint,
+}
+impl BigFraction {
+    pub fn new(numerator: BigInt, denominator: BigInt) -> Self {
+        if(denominator.is_zero()){
+            panic!("Denominator cannot be zero.");
+        }
+        let gcd = numerator.magnitude.gcd(&denominator.magnitude);
+        BigFraction {
+            p: BigInt::new(numerator.negative != denominator.negative, &numerator.magnitude / &gcd),
+            q: &denominator.magnitude / &gcd,
+        }
+    }
+    pub fn from_integer(value: BigInt) -> Self {
+        BigFraction { p: value, q: BigUint::one() }
+    }
+    pub fn numerator(&self) -> &BigInt {
+        &self.p
+    }
+    pub fn denominator(&self) -> &BigUint {
+        &self.q
+    }
+    pub fn is_zero(&self) -> bool {
+        self.p.is_zero()
+    }
+    pub fn recip(&self) -> Self {
+        BigFraction::new(BigInt::from(self.q.clone()), self.p.clone())
+    }
+    ///`None` when either part does not fit in T
+    pub fn to_fraction<T: Integer>(&self) -> Option<Fraction<T>> {
+        Fraction::checked_new(self.p.to_integer()?, BigInt::from(self.q.clone()).to_integer()?)
+    }
+}
+impl<T: Integer> From<Fraction<T>> for BigFraction {
+    fn from(value: Fraction<T>) -> Self {
+        BigFraction::new(BigInt::from_integer(value.numerator()), BigInt::from_integer(value.denominator()))
+    }
+}
+impl Ord for BigFraction {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let left = &self.p * &BigInt::from(other.q.clone());
+        let right = &other.p * &BigInt::from(self.q.clone());
+        left.cmp(&right)
+    }
+}
+impl PartialOrd for BigFraction {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Display for BigFraction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if(self.q == BigUint::one()){
+            write!(f, "{}", self.p)
+        }else{
+            write!(f, "{}/{}", self.p, self.q)
+        }
+    }
+}
+impl Debug for BigFraction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+impl Neg for BigFraction {
+    type Output = BigFraction;
+    fn neg(self) -> BigFraction {
+        BigFraction { p: -self.p, q: self.q }
+    }
+}
+impl_big_op!(BigFraction, Add, add, |a, b| {
+    let (aq, bq) = (BigInt::from(a.q.clone()), BigInt::from(b.q.clone()));
+    BigFraction::new(&(&a.p * &bq) + &(&b.p * &aq), &aq * &bq)
+});
+impl_big_op!(BigFraction, Sub, sub, |a, b| {
+    let (aq, bq) = (BigInt::from(a.q.clone()), BigInt::from(b.q.clone()));
+    BigFraction::new(&(&a.p * &bq) - &(&b.p * &aq), &aq * &bq)
+});
+impl_big_op!(BigFraction, Mul, mul, |a, b| {
+    BigFraction::new(&a.p * &b.p, BigInt::from(&a.q * &b.q))
+});
+impl_big_op!(BigFraction, Div, div, |a, b| {
+    if(b.is_zero()){
+        panic!("Cannot divide by zero fraction.");
+    }
+    BigFraction::new(&a.p * &BigInt::from(b.q.clone()), &BigInt::from(a.q.clone()) * &b.p)
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn big(s: &str) -> BigInt {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_biguint_arithmetic() {
+        let a: BigUint = "123456789012345678901234567890".parse().unwrap();
+        let b: BigUint = "987654321098765432109876543210".parse().unwrap();
+        assert_eq!((&a + &b).to_string(), "1111111110111111111011111111100");
+        assert_eq!((&b - &a).to_string(), "864197532086419753208641975320");
+        assert_eq!((&a * &b).to_string(), "121932631137021795226185032733622923332237463801111263526900");
+        let (q, r) = b.div_rem(&a);
+        assert_eq!((q.to_string(), r.to_string()), ("8".to_string(), "9000000000900000000090".to_string()));
+        assert_eq!(&(&q * &a) + &r, b);
+        assert_eq!(a.checked_sub(&b), None);
+        assert_eq!(a.checked_div_rem(&BigUint::zero()), None);
+        assert_eq!(BigUint::from(2u8).pow(100).to_string(), "1267650600228229401496703205376");
+        assert_eq!(BigUint::from(0u8).to_string(), "0");
+    }
+
+    #[test]
+    fn test_biguint_compare_and_convert() {
+        let a = BigUint::from(u128::MAX);
+        let b = &a + &BigUint::one();
+        assert!(b > a);
+        assert_eq!(a.to_u128(), Some(u128::MAX));
+        assert_eq!(b.to_u128(), None);
+        assert_eq!(BigUint::from(300u32).to_u64(), Some(300));
+        assert_eq!(BigUint::from(12u8).gcd(&BigUint::from(18u8)), BigUint::from(6u8));
+    }
+
+    #[test]
+    fn test_radix() {
+        let a = BigUint::from_str_radix("ffffffffffffffffffffffffffffffff", 16).unwrap();
+        assert_eq!(a, BigUint::from(u128::MAX));
+        assert_eq!(a.to_string_radix(2), "1".repeat(128));
+        assert_eq!(a.to_string_radix(36), u128::MAX.to_string().parse::<BigUint>().unwrap().to_string_radix(36));
+        assert_eq!(BigUint::from(255u8).to_string_radix(16), "ff");
+        assert_eq!(BigUint::from(35u8).to_string_radix(36), "z");
+        assert_eq!(BigUint::from(61u8).to_digits(62).get_u8_array(), &vec![61]);
+        assert!(BigUint::from_str_radix("12", 2).is_err());
+        assert!("".parse::<BigUint>().is_err());
+        assert_eq!(BigInt::from_str_radix("-zz", 36).unwrap(), big("-1295"));
+        assert_eq!(big("-1295").to_string_radix(36), "-zz");
+    }
+
+    #[test]
+    fn test_bigint() {
+        let a = big("-123456789012345678901234567890");
+        let b = big("1000000000000000000000");
+        assert_eq!((&a + &b).to_string(), "-123456788012345678901234567890");
+        assert_eq!((&b - &a).to_string(), "123456790012345678901234567890");
+        assert_eq!((&a * &b).to_string(), "-123456789012345678901234567890000000000000000000000");
+        assert_eq!((&a / &b).to_string(), "-123456789");
+        assert_eq!((&a % &b).to_string(), "-12345678901234567890");
+        assert_eq!(-&a, big("123456789012345678901234567890"));
+        assert_eq!(big("-2").pow(3), big("-8"));
+        assert!(a < b);
+        assert!(big("-3") < big("-2"));
+        assert_eq!(big("-0"), BigInt::zero());
+        assert_eq!(BigInt::from_integer(i128::MIN).to_string(), i128::MIN.to_string());
+        assert_eq!(BigInt::from_integer(u128::MAX).to_string(), u128::MAX.to_string());
+        assert_eq!(BigInt::from_integer(i128::MIN).to_integer::<i128>(), Some(i128::MIN));
+        assert_eq!(big("-5").to_integer::<i8>(), Some(-5));
+        assert_eq!(big("300").to_integer::<u8>(), None);
+    }
+
+    #[test]
+    fn test_big_fraction() {
+        let third = BigFraction::from(Fraction::new(1i64, 3));
+        let sixth = BigFraction::from(Fraction::new(1i64, 6));
+        assert_eq!((&third + &sixth).to_string(), "1/2");
+        assert_eq!((&sixth - &third).to_string(), "-1/6");
+        assert_eq!((&third / &sixth).to_string(), "2");
+        assert!(sixth < third);
+        //(2^64/3)^2 is far outside i64 but exact here
+        let x = BigFraction::new(BigInt::from(BigUint::from(2u8).pow(64)), big("3"));
+        let square = &x * &x;
+        assert_eq!(square.to_string(), "340282366920938463463374607431768211456/9");
+        assert_eq!(square.to_fraction::<i64>(), None);
+        let scale = BigFraction::from(Fraction::new(3i64, 1 << 62));
+        assert_eq!((&(&square * &scale) * &scale).to_fraction::<i64>(), Some(Fraction::from(16)));
+        assert_eq!(BigFraction::new(big("4"), big("-6")).to_fraction::<i32>(), Some(Fraction::new(-2, 3)));
+    }
+}
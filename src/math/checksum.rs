@@ -0,0 +1,304 @@
+use crate::math::digits::{Digits, DigitsError};
+
+///a check digit scheme. digits are read in writing order, so the check digit is index 0 of the `Digits`
+pub trait CheckDigit {
+    ///the check value for a payload that does not have it yet
+    fn compute(payload: &Digits) -> Result<u8, DigitsError>;
+    ///a full number, payload and check
+    fn validate(number: &Digits) -> Result<(), DigitsError>;
+}
+
+///digits in writing order after checking the base and, if given, the count
+fn read(digits: &Digits, base: u8, count: Option<usize>) -> Result<Vec<u8>, DigitsError> {
+    if(digits.max_digit() != base){
+        return Err(DigitsError::BaseMismatch(base, digits.max_digit()));
+    }
+    if(digits.len() == 0){
+        return Err(DigitsError::Empty);
+    }
+    if let Some(count) = count && digits.len() != count {
+        return Err(DigitsError::PlaceCountMismatch(digits.len(), count));
+    }
+    Ok(digits.get_u8_array().iter().rev().copied().collect())
+}
+///a number whose last place may be X (10), given in base 10 or, when it has an X, base 11
+fn read_with_x(number: &Digits, count: usize) -> Result<(Digits, u8), DigitsError> {
+    if(number.max_digit() != 10 && number.max_digit() != 11){
+        return Err(DigitsError::BaseMismatch(11, number.max_digit()));
+    }
+    if(number.len() != count){
+        return Err(DigitsError::PlaceCountMismatch(number.len(), count));
+    }
+    let digits = number.get_u8_array();
+    let payload = Digits::from_u8_array(digits[1..].to_vec(), 10)?;
+    Ok((payload, digits[0]))
+}
+fn check(expected: u8, found: u8) -> Result<(), DigitsError> {
+    if(expected == found){ Ok(()) }else{ Err(DigitsError::ChecksumMismatch(expected, found)) }
+}
+///splits off the last digit
+fn split_check(number: &Digits, base: u8) -> Result<(Digits, u8), DigitsError> {
+    read(number, base, None)?;
+    let digits = number.get_u8_array();
+    if(digits.len() < 2){
+        return Err(DigitsError::PlaceCountMismatch(digits.len(), 2));
+    }
+    Ok((Digits::from_u8_array(digits[1..].to_vec(), base)?, digits[0]))
+}
+
+///Luhn mod 10, used by payment cards
+pub struct Luhn;
+impl CheckDigit for Luhn {
+    fn compute(payload: &Digits) -> Result<u8, DigitsError> {
+        read(payload, 10, None)?;
+        //the check digit will take the rightmost place, so doubling starts at the payload's last digit
+        let sum: u32 = payload.get_u8_array().iter().enumerate().map(|(i, d)| {
+            let d = *d as u32;
+            if(i % 2 == 0){
+                let doubled = d * 2;
+                if(doubled > 9){ doubled - 9 }else{ doubled }
+            }else{
+                d
+            }
+        }).sum();
+        Ok(((10 - sum % 10) % 10) as u8)
+    }
+    fn validate(number: &Digits) -> Result<(), DigitsError> {
+        let (payload, found) = split_check(number, 10)?;
+        check(Self::compute(&payload)?, found)
+    }
+}
+
+///EAN-8, EAN-13, UPC-A and the like: weights 3, 1, 3, ... from the right of the payload
+pub struct Ean;
+impl CheckDigit for Ean {
+    fn compute(payload: &Digits) -> Result<u8, DigitsError> {
+        read(payload, 10, None)?;
+        let sum: u32 = payload.get_u8_array().iter().enumerate()
+            .map(|(i, d)| *d as u32 * if(i % 2 == 0){ 3 }else{ 1 }).sum();
+        Ok(((10 - sum % 10) % 10) as u8)
+    }
+    fn validate(number: &Digits) -> Result<(), DigitsError> {
+        let (payload, found) = split_check(number, 10)?;
+        check(Self::compute(&payload)?, found)
+    }
+}
+
+///ISBN-13, an EAN-13 of 12 payload digits
+pub struct Isbn13;
+impl CheckDigit for Isbn13 {
+    fn compute(payload: &Digits) -> Result<u8, DigitsError> {
+        read(payload, 10, Some(12))?;
+        Ean::compute(payload)
+    }
+    fn validate(number: &Digits) -> Result<(), DigitsError> {
+        read(number, 10, Some(13))?;
+        Ean::validate(number)
+    }
+}
+
+///ISBN-10, mod 11 with X for 10
+pub struct Isbn10;
+impl CheckDigit for Isbn10 {
+    fn compute(payload: &Digits) -> Result<u8, DigitsError> {
+        let digits = read(payload, 10, Some(9))?;
+        let sum: u32 = digits.iter().enumerate().map(|(i, d)| (i as u32 + 1) * *d as u32).sum();
+        Ok((sum % 11) as u8)
+    }
+    fn validate(number: &Digits) -> Result<(), DigitsError> {
+        let (payload, found) = read_with_x(number, 10)?;
+        check(Self::compute(&payload)?, found)
+    }
+}
+
+///Chinese resident identity number (GB 11643-1999): 17 digits weighted by 2^k mod 11, then mod 11 with X for 10
+pub struct ChineseId;
+const CHINESE_ID_WEIGHTS: [u32; 17] = [7, 9, 10, 5, 8, 4, 2, 1, 6, 3, 7, 9, 10, 5, 8, 4, 2];
+impl CheckDigit for ChineseId {
+    fn compute(payload: &Digits) -> Result<u8, DigitsError> {
+        let digits = read(payload, 10, Some(17))?;
+        let sum: u32 = digits.iter().zip(CHINESE_ID_WEIGHTS).map(|(d, w)| *d as u32 * w).sum();
+        Ok(((12 - sum % 11) % 11) as u8)
+    }
+    fn validate(number: &Digits) -> Result<(), DigitsError> {
+        let (payload, found) = read_with_x(number, 18)?;
+        check(Self::compute(&payload)?, found)
+    }
+}
+
+///IBAN mod 97-10 (ISO 7064). digits are base 36 with letters as 10..35, as read by a
+///case-insensitive 0-9a-z alphabet; the check value is the two digits after the country code
+pub struct Iban;
+impl Iban {
+    ///remainder mod 97 of the digits written out in decimal, letters taking two places
+    fn mod97<'a>(digits: impl Iterator<Item = &'a u8>) -> u32 {
+        digits.fold(0, |acc, d| {
+            let d = *d as u32;
+            if(d < 10){ (acc * 10 + d) % 97 }else{ (acc * 100 + d) % 97 }
+        })
+    }
+}
+impl CheckDigit for Iban {
+    ///`payload` is the IBAN without its two check digits
+    fn compute(payload: &Digits) -> Result<u8, DigitsError> {
+        let digits = read(payload, 36, None)?;
+        if(digits.len() < 3 || digits.len() > 32){
+            return Err(DigitsError::PlaceCountMismatch(digits.len(), digits.len().clamp(3, 32)));
+        }
+        //country code moves behind the account part, followed by 00 for the check digits
+        let rearranged = digits[2..].iter().chain(&digits[..2]).chain(&[0, 0]);
+        Ok((98 - Self::mod97(rearranged)) as u8)
+    }
+    fn validate(number: &Digits) -> Result<(), DigitsError> {
+        let digits = read(number, 36, None)?;
+        if(digits.len() < 5 || digits.len() > 34){
+            return Err(DigitsError::PlaceCountMismatch(digits.len(), digits.len().clamp(5, 34)));
+        }
+        if(digits[2] > 9 || digits[3] > 9){
+            return Err(DigitsError::DigitExceedLimit(digits[2].max(digits[3]), 10));
+        }
+        let found = digits[2] * 10 + digits[3];
+        let payload: Vec<u8> = digits[..2].iter().chain(&digits[4..]).rev().copied().collect();
+        check(Self::compute(&Digits::from_u8_array(payload, 36)?)?, found)
+    }
+}
+
+///Verhoeff, based on the dihedral group D5; catches every single error and adjacent swap
+pub struct Verhoeff;
+const VERHOEFF_D: [[u8; 10]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+    [1, 2, 3, 4, 0, 6, 7, 8, 9, 5],
+    [2, 3, 4, 0, 1, 7, 8, 9, 5, 6],
+    [3, 4, 0, 1, 2, 8, 9, 5, 6, 7],
+    [4, 0, 1, 2, 3, 9, 5, 6, 7, 8],
+    [5, 9, 8, 7, 6, 0, 4, 3, 2, 1],
+    [6, 5, 9, 8, 7, 1, 0, 4, 3, 2],
+    [7, 6, 5, 9, 8, 2, 1, 0, 4, 3],
+    [8, 7, 6, 5, 9, 3, 2, 1, 0, 4],
+    [9, 8, 7, 6, 5, 4, 3, 2, 1, 0],
+];
+const VERHOEFF_P: [[u8; 10]; 8] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+    [1, 5, 7, 6, 2, 8, 3, 0, 9, 4],
+    [5, 8, 0, 3, 7, 9, 6, 1, 4, 2],
+    [8, 9, 1, 6, 0, 4, 3, 5, 2, 7],
+    [9, 4, 5, 3, 1, 2, 6, 8, 7, 0],
+    [4, 2, 8, 6, 5, 7, 3, 9, 0, 1],
+    [2, 7, 9, 3, 8, 0, 6, 4, 1, 5],
+    [7, 0, 4, 6, 9, 1, 3, 2, 5, 8],
+];
+const VERHOEFF_INV: [u8; 10] = [0, 4, 3, 2, 1, 5, 6, 7, 8, 9];
+impl Verhoeff {
+    ///digits from the right, the first one permuted `offset` times
+    fn fold(digits: &[u8], offset: usize) -> u8 {
+        digits.iter().enumerate().fold(0, |c, (i, d)| {
+            VERHOEFF_D[c as usize][VERHOEFF_P[(i + offset) % 8][*d as usize] as usize]
+        })
+    }
+}
+impl CheckDigit for Verhoeff {
+    fn compute(payload: &Digits) -> Result<u8, DigitsError> {
+        read(payload, 10, None)?;
+        Ok(VERHOEFF_INV[Self::fold(payload.get_u8_array(), 1) as usize])
+    }
+    fn validate(number: &Digits) -> Result<(), DigitsError> {
+        let (payload, found) = split_check(number, 10)?;
+        if(Self::fold(number.get_u8_array(), 0) == 0){
+            Ok(())
+        }else{
+            Err(DigitsError::ChecksumMismatch(Self::compute(&payload)?, found))
+        }
+    }
+}
+
+///Damm, a totally anti-symmetric quasigroup of order 10
+pub struct Damm;
+const DAMM: [[u8; 10]; 10] = [
+    [0, 3, 1, 7, 5, 9, 8, 6, 4, 2],
+    [7, 0, 9, 2, 1, 5, 4, 8, 6, 3],
+    [4, 2, 0, 6, 8, 7, 1, 3, 5, 9],
+    [1, 7, 5, 0, 9, 8, 3, 4, 2, 6],
+    [6, 1, 2, 3, 0, 4, 5, 9, 7, 8],
+    [3, 6, 7, 4, 2, 0, 9, 5, 8, 1],
+    [5, 8, 6, 9, 7, 2, 0, 1, 3, 4],
+    [8, 9, 4, 5, 3, 6, 2, 0, 1, 7],
+    [9, 4, 3, 8, 6, 1, 7, 2, 0, 5],
+    [2, 5, 8, 1, 4, 3, 6, 7, 9, 0],
+];
+impl CheckDigit for Damm {
+    fn compute(payload: &Digits) -> Result<u8, DigitsError> {
+        let digits = read(payload, 10, None)?;
+        Ok(digits.iter().fold(0, |interim, d| DAMM[interim as usize][*d as usize]))
+    }
+    fn validate(number: &Digits) -> Result<(), DigitsError> {
+        let (payload, found) = split_check(number, 10)?;
+        check(Self::compute(&payload)?, found)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::alphabet::CharTable;
+    use crate::math::digit_format::ParseOptions;
+    use crate::math::digits::char_to_arabic_num;
+
+    fn decimal(s: &str) -> Digits {
+        Digits::from_string(s, 10, char_to_arabic_num).unwrap()
+    }
+    fn with_x(s: &str) -> Digits {
+        let base = if(s.ends_with('X')){ 11 }else{ 10 };
+        Digits::from_string_with(s, base, &CharTable::new("0123456789X")).unwrap()
+    }
+    fn iban(s: &str) -> Digits {
+        let alphabet = CharTable::new("0123456789abcdefghijklmnopqrstuvwxyz").ignore_case();
+        Digits::parse(s, 36, &ParseOptions::new().separators(" "), &alphabet).unwrap()
+    }
+
+    #[test]
+    fn test_luhn_and_ean() {
+        assert_eq!(Luhn::compute(&decimal("7992739871")), Ok(3));
+        assert_eq!(Luhn::validate(&decimal("79927398713")), Ok(()));
+        assert_eq!(Luhn::validate(&decimal("79927398710")), Err(DigitsError::ChecksumMismatch(3, 0)));
+        assert_eq!(Luhn::validate(&decimal("4111111111111111")), Ok(()));
+        assert_eq!(Ean::compute(&decimal("400638133393")), Ok(1));
+        assert_eq!(Ean::validate(&decimal("036000291452")), Ok(()));
+        assert_eq!(Ean::validate(&decimal("96385074")), Ok(()));
+        assert_eq!(Isbn13::validate(&decimal("9780306406157")), Ok(()));
+        assert_eq!(Isbn13::compute(&decimal("978030640615")), Ok(7));
+        assert_eq!(Isbn13::validate(&decimal("978030640615")), Err(DigitsError::PlaceCountMismatch(12, 13)));
+        assert_eq!(Luhn::compute(&Digits::from_u64(12, 16)), Err(DigitsError::BaseMismatch(10, 16)));
+    }
+
+    #[test]
+    fn test_mod11() {
+        assert_eq!(Isbn10::compute(&decimal("030640615")), Ok(2));
+        assert_eq!(Isbn10::validate(&with_x("0306406152")), Ok(()));
+        assert_eq!(Isbn10::validate(&with_x("080442957X")), Ok(()));
+        assert_eq!(Isbn10::validate(&with_x("0804429570")), Err(DigitsError::ChecksumMismatch(10, 0)));
+        assert_eq!(ChineseId::compute(&decimal("11010519491231002")), Ok(10));
+        assert_eq!(ChineseId::validate(&with_x("11010519491231002X")), Ok(()));
+        assert_eq!(ChineseId::validate(&decimal("440524188001010014")), Ok(()));
+        assert!(ChineseId::validate(&decimal("440524188001010015")).is_err());
+    }
+
+    #[test]
+    fn test_iban() {
+        assert_eq!(Iban::validate(&iban("GB82 WEST 1234 5698 7654 32")), Ok(()));
+        assert_eq!(Iban::validate(&iban("de89370400440532013000")), Ok(()));
+        assert_eq!(Iban::validate(&iban("GB83 WEST 1234 5698 7654 32")), Err(DigitsError::ChecksumMismatch(82, 83)));
+        assert_eq!(Iban::compute(&iban("GBWEST12345698765432")), Ok(82));
+    }
+
+    #[test]
+    fn test_verhoeff_and_damm() {
+        assert_eq!(Verhoeff::compute(&decimal("236")), Ok(3));
+        assert_eq!(Verhoeff::validate(&decimal("2363")), Ok(()));
+        assert_eq!(Verhoeff::validate(&decimal("2364")), Err(DigitsError::ChecksumMismatch(3, 4)));
+        //adjacent swaps are caught
+        assert!(Verhoeff::validate(&decimal("3263")).is_err());
+        assert_eq!(Damm::compute(&decimal("572")), Ok(4));
+        assert_eq!(Damm::validate(&decimal("5724")), Ok(()));
+        assert!(Damm::validate(&decimal("7524")).is_err());
+    }
+}
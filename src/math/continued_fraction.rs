@@ -0,0 +1,268 @@
+use std::collections::HashMap;
+use crate::math::fraction::Fraction;
+use crate::math::mathtool::isqrt;
+use crate::math::traits::Integer;
+
+///floor(a/b) for any signs of a and b
+fn div_floor<T: Integer>(a: T, b: T) -> T {
+    let q = a / b;
+    let r = a % b;
+    if(r != T::ZERO && r.is_negative() != b.is_negative()){
+        q - T::ONE
+    }else{
+        q
+    }
+}
+
+///lazy terms of the simple continued fraction of a fraction, by the euclidean algorithm.
+///the last term is never 1 unless it is the only one
+pub struct Terms<T> {
+    p: T,
+    q: T,
+}
+impl<T: Integer> Iterator for Terms<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if(self.q == T::ZERO){
+            return None;
+        }
+        let a = div_floor(self.p, self.q);
+        //euclidean remainder, written without a*q so it cannot overflow
+        let mut r = self.p % self.q;
+        if(r.is_negative()){
+            r = r + self.q;
+        }
+        self.p = self.q;
+        self.q = r;
+        Some(a)
+    }
+}
+
+///h/k recurrence over a stream of terms: h_n = a_n*h_{n-1} + h_{n-2}
+#[derive(Copy, Clone)]
+struct Recurrence<T> {
+    h0: T,
+    k0: T,
+    h1: T,
+    k1: T,
+}
+impl<T: Integer> Recurrence<T> {
+    fn new() -> Self {
+        Recurrence { h0: T::ZERO, k0: T::ONE, h1: T::ONE, k1: T::ZERO }
+    }
+    ///(h_{n-2} + j*h_{n-1}) / (k_{n-2} + j*k_{n-1})
+    fn intermediate(&self, j: T) -> Option<(T, T)> {
+        Some((
+            j.checked_mul(self.h1)?.checked_add(self.h0)?,
+            j.checked_mul(self.k1)?.checked_add(self.k0)?,
+        ))
+    }
+    fn push(&mut self, h: T, k: T) {
+        *self = Recurrence { h0: self.h1, k0: self.k1, h1: h, k1: k };
+    }
+}
+
+///lazy convergents of any sequence of continued fraction terms, ends early on overflow
+pub struct Convergents<I: Iterator> {
+    terms: I,
+    state: Recurrence<I::Item>,
+}
+impl<T: Integer, I: Iterator<Item = T>> Iterator for Convergents<I> {
+    type Item = Fraction<T>;
+
+    fn next(&mut self) -> Option<Fraction<T>> {
+        let a = self.terms.next()?;
+        let (h, k) = self.state.intermediate(a)?;
+        self.state.push(h, k);
+        Fraction::checked_new(h, k)
+    }
+}
+pub fn convergents<T: Integer, I: IntoIterator<Item = T>>(terms: I) -> Convergents<I::IntoIter> {
+    Convergents { terms: terms.into_iter(), state: Recurrence::new() }
+}
+
+///lazy semiconvergents: for every term a_n after the first, the fractions
+///(h_{n-2} + j*h_{n-1}) / (k_{n-2} + j*k_{n-1}) for j = 1..=a_n, the last one being the convergent
+pub struct SemiConvergents<I: Iterator> {
+    terms: I,
+    state: Recurrence<I::Item>,
+    //current term and the next j to emit for it
+    pending: Option<(I::Item, I::Item)>,
+    first: bool,
+}
+impl<T: Integer, I: Iterator<Item = T>> Iterator for SemiConvergents<I> {
+    type Item = Fraction<T>;
+
+    fn next(&mut self) -> Option<Fraction<T>> {
+        loop {
+            if let Some((a, j)) = self.pending {
+                if(j <= a){
+                    let (h, k) = self.state.intermediate(j)?;
+                    if(j == a){
+                        self.state.push(h, k);
+                        self.pending = None;
+                    }else{
+                        self.pending = Some((a, j + T::ONE));
+                    }
+                    return Fraction::checked_new(h, k);
+                }
+                self.pending = None;
+            }
+            let a = self.terms.next()?;
+            if(self.first){
+                //the leading term has no intermediate fractions
+                self.first = false;
+                self.state.push(a, T::ONE);
+                return Some(Fraction::from_integer(a));
+            }
+            self.pending = Some((a, T::ONE));
+        }
+    }
+}
+pub fn semiconvergents<T: Integer, I: IntoIterator<Item = T>>(terms: I) -> SemiConvergents<I::IntoIter> {
+    SemiConvergents { terms: terms.into_iter(), state: Recurrence::new(), pending: None, first: true }
+}
+
+impl<T: Integer> Fraction<T> {
+    pub fn continued_fraction_terms(&self) -> Terms<T> {
+        Terms { p: self.numerator(), q: self.denominator() }
+    }
+
+    ///[a0; a1, a2, ...] with a0 = floor(self)
+    pub fn to_continued_fraction(self) -> Vec<T> {
+        self.continued_fraction_terms().collect()
+    }
+
+    ///value of [a0; a1, ...], `None` when empty, when it overflows or when it has no value (like [0; 0])
+    pub fn from_continued_fraction(terms: &[T]) -> Option<Self> {
+        if(terms.is_empty()){
+            return None;
+        }
+        let mut state = Recurrence::new();
+        for a in terms {
+            let (h, k) = state.intermediate(*a)?;
+            state.push(h, k);
+        }
+        Fraction::checked_new(state.h1, state.k1)
+    }
+
+    pub fn convergents(&self) -> Convergents<Terms<T>> {
+        convergents(self.continued_fraction_terms())
+    }
+
+    pub fn semiconvergents(&self) -> SemiConvergents<Terms<T>> {
+        semiconvergents(self.continued_fraction_terms())
+    }
+}
+
+///√n = [a0; (period)], the period is empty when n is a perfect square
+pub fn sqrt_continued_fraction<T: Integer>(n: T) -> (T, Vec<T>) {
+    let a0 = isqrt(n);
+    let mut period = Vec::new();
+    if(a0 * a0 == n){
+        return (a0, period);
+    }
+    //all of m, d, a stay below 2*a0, so nothing here can overflow
+    let (mut m, mut d, mut a) = (T::ZERO, T::ONE, a0);
+    while a != a0 + a0 {
+        m = d * a - m;
+        d = (n - m * m) / d;
+        a = (a0 + m) / d;
+        period.push(a);
+    }
+    (a0, period)
+}
+
+///(p + √d) / q as (pre-period terms, periodic terms), `None` on overflow or q == 0.
+///a perfect square d gives the finite expansion of the rational value with an empty period
+pub fn quadratic_continued_fraction<T: Integer>(mut p: T, mut d: T, mut q: T) -> Option<(Vec<T>, Vec<T>)> {
+    if(q == T::ZERO){
+        return None;
+    }
+    let s = isqrt(d);
+    if(s * s == d){
+        return Some((Fraction::checked_new(p.checked_add(s)?, q)?.to_continued_fraction(), Vec::new()));
+    }
+    //the recurrence needs q | d - p^2, scaling everything by |q| makes it so
+    if((d.checked_sub(p.checked_mul(p)?)?) % q != T::ZERO){
+        let abs_q = q.abs();
+        d = d.checked_mul(q.checked_mul(q)?)?;
+        p = p.checked_mul(abs_q)?;
+        q = q.checked_mul(abs_q)?;
+    }
+    let s = isqrt(d);
+    let mut seen = HashMap::new();
+    let mut terms = Vec::new();
+    loop {
+        if let Some(start) = seen.get(&(p, q)) {
+            let period = terms.split_off(*start);
+            return Some((terms, period));
+        }
+        seen.insert((p, q), terms.len());
+        //√d lies strictly between s and s+1
+        let a = if(q.is_negative()){
+            div_floor(p.checked_add(s)?.checked_add(T::ONE)?, q)
+        }else{
+            div_floor(p.checked_add(s)?, q)
+        };
+        terms.push(a);
+        p = a.checked_mul(q)?.checked_sub(p)?;
+        q = d.checked_sub(p.checked_mul(p)?)? / q;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let f = Fraction::new(415i64, 93);
+        assert_eq!(f.to_continued_fraction(), [4, 2, 6, 7]);
+        assert_eq!(Fraction::from_continued_fraction(&[4i64, 2, 6, 7]), Some(f));
+        let g = Fraction::new(-7i32, 3);
+        assert_eq!(g.to_continued_fraction(), [-3, 1, 2]);
+        assert_eq!(Fraction::from_continued_fraction(&g.to_continued_fraction()), Some(g));
+        assert_eq!(Fraction::from(5u8).to_continued_fraction(), [5]);
+        assert_eq!(Fraction::from_integer(i8::MIN).to_continued_fraction(), [-128]);
+        assert_eq!(Fraction::new(i8::MIN, 3).to_continued_fraction(), [-43, 3]);
+        assert_eq!(Fraction::<i64>::from_continued_fraction(&[]), None);
+        assert_eq!(Fraction::<i8>::from_continued_fraction(&[100, 100]), None);
+    }
+
+    #[test]
+    fn test_convergents() {
+        let f = Fraction::new(415i64, 93);
+        let c: Vec<_> = f.convergents().collect();
+        assert_eq!(c, [Fraction::from(4), Fraction::new(9, 2), Fraction::new(58, 13), f]);
+        let s: Vec<_> = f.semiconvergents().take(5).collect();
+        assert_eq!(s, [Fraction::from(4), Fraction::from(5), Fraction::new(9, 2), Fraction::new(13, 3), Fraction::new(22, 5)]);
+        assert_eq!(f.semiconvergents().last(), Some(f));
+        assert_eq!(f.semiconvergents().count(), 1 + 2 + 6 + 7);
+    }
+
+    #[test]
+    fn test_sqrt() {
+        assert_eq!(sqrt_continued_fraction(2u32), (1, vec![2]));
+        assert_eq!(sqrt_continued_fraction(7i64), (2, vec![1, 1, 1, 4]));
+        assert_eq!(sqrt_continued_fraction(13i64), (3, vec![1, 1, 1, 1, 6]));
+        assert_eq!(sqrt_continued_fraction(16i64), (4, vec![]));
+        //pell solutions of x^2 - 2y^2 = ±1 come out of the infinite expansion
+        let (a0, period) = sqrt_continued_fraction(2i64);
+        let terms = std::iter::once(a0).chain(period.into_iter().cycle());
+        let c: Vec<_> = convergents(terms).take(4).collect();
+        assert_eq!(c, [Fraction::from(1), Fraction::new(3, 2), Fraction::new(7, 5), Fraction::new(17, 12)]);
+    }
+
+    #[test]
+    fn test_quadratic() {
+        //golden ratio
+        assert_eq!(quadratic_continued_fraction(1i64, 5, 2), Some((vec![], vec![1])));
+        assert_eq!(quadratic_continued_fraction(0i64, 2, 1), Some((vec![1], vec![2])));
+        //(1+√3)/-1 = -2.732..
+        assert_eq!(quadratic_continued_fraction(1i64, 3, -1), Some((vec![-3, 3], vec![1, 2])));
+        assert_eq!(quadratic_continued_fraction(1i64, 9, 8), Some((vec![0, 2], vec![])));
+        assert_eq!(quadratic_continued_fraction(1i64, 9, 0), None);
+    }
+}
@@ -0,0 +1,357 @@
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display, Formatter};
+use std::ops::{Add, Mul, Neg, Sub};
+use std::str::FromStr;
+use crate::math::bigint::{BigFraction, BigInt, BigUint};
+use crate::math::digits::{Digits, DigitsError};
+use crate::math::fraction::{Fraction, RoundingMode};
+use crate::math::traits::Integer;
+
+///exact base-10 number: (-1)^negative * coefficient / 10^scale.
+///the scale is kept as given, so 1.50 and 1.5 are equal but print differently
+#[derive(Clone)]
+pub struct Decimal {
+    negative: bool,
+    coefficient: Digits,
+    scale: u32,
+}
+
+///n/d rounded to an integer, d > 0
+fn round_div(n: &BigInt, d: &BigUint, mode: RoundingMode) -> BigInt {
+    let d = BigInt::from(d.clone());
+    let (mut floor, mut r) = n.div_rem(&d);
+    if(r.is_negative()){
+        floor = &floor - &BigInt::one();
+        r = &r + &d;
+    }
+    if(r.is_zero()){
+        return floor;
+    }
+    let up = match mode {
+        RoundingMode::Floor => false,
+        RoundingMode::Ceil => true,
+        RoundingMode::Trunc => floor.is_negative(),
+        _ => match (&r + &r).cmp(&d) {
+            Ordering::Less => false,
+            Ordering::Greater => true,
+            Ordering::Equal => match mode {
+                RoundingMode::HalfUp => true,
+                RoundingMode::HalfDown => false,
+                RoundingMode::HalfEven => !(&floor % &BigInt::from_integer(2)).is_zero(),
+                RoundingMode::HalfAwayFromZero => !floor.is_negative(),
+                _ => floor.is_negative(),
+            },
+        },
+    };
+    if(up){ &floor + &BigInt::one() }else{ floor }
+}
+
+impl Decimal {
+    ///`coefficient` must be in base 10
+    pub fn new(negative: bool, coefficient: Digits, scale: u32) -> Result<Decimal, DigitsError> {
+        if(coefficient.max_digit() != 10){
+            return Err(DigitsError::BaseMismatch(10, coefficient.max_digit()));
+        }
+        Ok(Self::from_big(&BigInt::new(negative, BigUint::from_digits(&coefficient)), scale))
+    }
+    fn from_big(value: &BigInt, scale: u32) -> Decimal {
+        Decimal {
+            negative: value.is_negative(),
+            coefficient: value.magnitude().to_digits(10),
+            scale,
+        }
+    }
+    ///the signed coefficient
+    fn to_big(&self) -> BigInt {
+        BigInt::new(self.negative, BigUint::from_digits(&self.coefficient))
+    }
+    fn ten_pow(exp: u32) -> BigUint {
+        BigUint::from(10u8).pow(exp)
+    }
+    ///both coefficients over the larger scale
+    fn aligned(&self, other: &Decimal) -> (BigInt, BigInt, u32) {
+        let scale = self.scale.max(other.scale);
+        let widen = |d: &Decimal| &d.to_big() * &BigInt::from(Self::ten_pow(scale - d.scale));
+        (widen(self), widen(other), scale)
+    }
+
+    pub fn zero() -> Decimal {
+        Self::from_big(&BigInt::zero(), 0)
+    }
+    pub fn from_integer<T: Integer>(value: T) -> Decimal {
+        Self::from_big(&BigInt::from_integer(value), 0)
+    }
+    pub fn is_zero(&self) -> bool {
+        self.coefficient.len() == 0
+    }
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+    ///base-10 digits without leading zeros, empty for zero
+    pub fn coefficient(&self) -> &Digits {
+        &self.coefficient
+    }
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+    ///digits before the point, empty when the magnitude is below one
+    pub fn integer_digits(&self) -> Digits {
+        let digits = self.coefficient.get_u8_array();
+        let scale = (self.scale as usize).min(digits.len());
+        Digits::from_u8_array(digits[scale..].to_vec(), 10).expect("coefficient is decimal")
+    }
+    ///exactly `scale` digits after the point, zeros included
+    pub fn fraction_digits(&self) -> Digits {
+        let digits = self.coefficient.get_u8_array();
+        let mut fraction: Vec<u8> = digits.iter().take(self.scale as usize).copied().collect();
+        fraction.resize(self.scale as usize, 0);
+        Digits::from_u8_array(fraction, 10).expect("coefficient is decimal")
+    }
+
+    ///the same value with `scale` places, rounding when places are dropped
+    pub fn rescale(&self, scale: u32, mode: RoundingMode) -> Decimal {
+        if(scale >= self.scale){
+            return Self::from_big(&(&self.to_big() * &BigInt::from(Self::ten_pow(scale - self.scale))), scale);
+        }
+        Self::from_big(&round_div(&self.to_big(), &Self::ten_pow(self.scale - scale), mode), scale)
+    }
+    ///self / other with `scale` places, `None` for a zero divisor
+    pub fn div_to_scale(&self, other: &Decimal, scale: u32, mode: RoundingMode) -> Option<Decimal> {
+        if(other.is_zero()){
+            return None;
+        }
+        //self/other = (a/10^s) / (b/10^t), so the result coefficient is a*10^(scale+t-s) / b
+        let mut n = &self.to_big() * &BigInt::from(Self::ten_pow(scale + other.scale));
+        let d = &Self::ten_pow(self.scale) * other.to_big().magnitude();
+        if(other.negative){
+            n = -n;
+        }
+        Some(Self::from_big(&round_div(&n, &d, mode), scale))
+    }
+
+    pub fn abs(&self) -> Decimal {
+        Self::from_big(&self.to_big().abs(), self.scale)
+    }
+
+    ///exact value, `None` when it does not fit in T
+    pub fn to_fraction<T: Integer>(&self) -> Option<Fraction<T>> {
+        self.to_big_fraction().to_fraction()
+    }
+    pub fn to_big_fraction(&self) -> BigFraction {
+        BigFraction::new(self.to_big(), BigInt::from(Self::ten_pow(self.scale)))
+    }
+    ///fraction rounded to `scale` places
+    pub fn from_fraction<T: Integer>(value: &Fraction<T>, scale: u32, mode: RoundingMode) -> Decimal {
+        let n = &BigInt::from_integer(value.numerator()) * &BigInt::from(Self::ten_pow(scale));
+        let d = BigInt::from_integer(value.denominator());
+        Self::from_big(&round_div(&n, d.magnitude(), mode), scale)
+    }
+    ///the shortest exact decimal, `None` when the expansion does not terminate (like 1/3)
+    pub fn from_fraction_exact<T: Integer>(value: &Fraction<T>) -> Option<Decimal> {
+        //terminates iff q = 2^a * 5^b, and then max(a, b) places are needed
+        let mut q = BigInt::from_integer(value.denominator()).magnitude().clone();
+        let (two, five) = (BigUint::from(2u8), BigUint::from(5u8));
+        let (mut twos, mut fives) = (0, 0);
+        while (&q % &two).is_zero() {
+            q = &q / &two;
+            twos += 1;
+        }
+        while (&q % &five).is_zero() {
+            q = &q / &five;
+            fives += 1;
+        }
+        if(q != BigUint::one()){
+            return None;
+        }
+        Some(Self::from_fraction(value, u32::max(twos, fives), RoundingMode::Trunc))
+    }
+}
+
+impl Default for Decimal {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+impl PartialEq for Decimal {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+impl Eq for Decimal {}
+impl Ord for Decimal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (a, b, _) = self.aligned(other);
+        a.cmp(&b)
+    }
+}
+impl PartialOrd for Decimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Display for Decimal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut digits = self.coefficient.cast_to_string();
+        let scale = self.scale as usize;
+        if(digits.len() <= scale){
+            digits = format!("{}{digits}", "0".repeat(scale + 1 - digits.len()));
+        }
+        if(scale > 0){
+            digits.insert(digits.len() - scale, '.');
+        }
+        f.pad_integral(!self.negative, "", &digits)
+    }
+}
+impl Debug for Decimal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+///[+-]digits[.digits], the scale is the number of digits after the point
+impl FromStr for Decimal {
+    type Err = DigitsError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, rest) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        let (int, frac) = rest.split_once('.').unwrap_or((rest, ""));
+        if(int.is_empty() && frac.is_empty()){
+            return Err(DigitsError::Empty);
+        }
+        let all = format!("{int}{frac}");
+        if let Some(c) = all.chars().find(|c| !c.is_ascii_digit()) {
+            return Err(DigitsError::NoConversionCharToNumRule(c));
+        }
+        let coefficient: BigUint = all.parse()?;
+        Ok(Self::from_big(&BigInt::new(negative, coefficient), frac.len() as u32))
+    }
+}
+///a fraction whose decimal expansion does not terminate, use `Decimal::from_fraction` to round it
+#[derive(PartialEq, Eq)]
+pub struct NonTerminatingError;
+impl Debug for NonTerminatingError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "fraction has no finite decimal expansion")
+    }
+}
+impl Display for NonTerminatingError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+impl std::error::Error for NonTerminatingError {}
+///exact only, see `from_fraction_exact`
+impl<T: Integer> TryFrom<Fraction<T>> for Decimal {
+    type Error = NonTerminatingError;
+    fn try_from(value: Fraction<T>) -> Result<Self, Self::Error> {
+        Self::from_fraction_exact(&value).ok_or(NonTerminatingError)
+    }
+}
+impl Neg for Decimal {
+    type Output = Decimal;
+    fn neg(self) -> Decimal {
+        Self::from_big(&-self.to_big(), self.scale)
+    }
+}
+impl Add for &Decimal {
+    type Output = Decimal;
+    ///exact, with the larger scale of the two
+    fn add(self, other: &Decimal) -> Decimal {
+        let (a, b, scale) = self.aligned(other);
+        Decimal::from_big(&(&a + &b), scale)
+    }
+}
+impl Sub for &Decimal {
+    type Output = Decimal;
+    fn sub(self, other: &Decimal) -> Decimal {
+        let (a, b, scale) = self.aligned(other);
+        Decimal::from_big(&(&a - &b), scale)
+    }
+}
+impl Mul for &Decimal {
+    type Output = Decimal;
+    ///exact, the scales add up
+    fn mul(self, other: &Decimal) -> Decimal {
+        Decimal::from_big(&(&self.to_big() * &other.to_big()), self.scale + other.scale)
+    }
+}
+impl Add for Decimal {
+    type Output = Decimal;
+    fn add(self, other: Decimal) -> Decimal {
+        &self + &other
+    }
+}
+impl Sub for Decimal {
+    type Output = Decimal;
+    fn sub(self, other: Decimal) -> Decimal {
+        &self - &other
+    }
+}
+impl Mul for Decimal {
+    type Output = Decimal;
+    fn mul(self, other: Decimal) -> Decimal {
+        &self * &other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d(s: &str) -> Decimal {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_display() {
+        assert_eq!(d("123.4500").to_string(), "123.4500");
+        assert_eq!(d("123.4500").scale(), 4);
+        assert_eq!(d("-0.05").to_string(), "-0.05");
+        assert_eq!(d("+.5").to_string(), "0.5");
+        assert_eq!(d("-0.00").to_string(), "0.00");
+        assert_eq!(d("7").to_string(), "7");
+        assert_eq!(format!("{:>8}", d("-1.5")), "    -1.5");
+        assert!("1.2.3".parse::<Decimal>().is_err());
+        assert!("-".parse::<Decimal>().is_err());
+        assert!("1e3".parse::<Decimal>().is_err());
+        assert_eq!(d("1.50"), d("1.5"));
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        assert_eq!((d("0.1") + d("0.2")).to_string(), "0.3");
+        assert_eq!((d("19.99") - d("20")).to_string(), "-0.01");
+        assert_eq!((d("1.25") * d("-0.4")).to_string(), "-0.500");
+        let big = d("99999999999999999999999999.99");
+        assert_eq!((&big + &d("0.01")).to_string(), "100000000000000000000000000.00");
+        assert!(d("-2") < d("1.5"));
+    }
+
+    #[test]
+    fn test_division_rounding() {
+        let (one, three) = (d("1"), d("3"));
+        assert_eq!(one.div_to_scale(&three, 4, RoundingMode::HalfEven).unwrap().to_string(), "0.3333");
+        assert_eq!(d("2").div_to_scale(&three, 2, RoundingMode::Floor).unwrap().to_string(), "0.66");
+        assert_eq!(d("-2").div_to_scale(&three, 2, RoundingMode::Floor).unwrap().to_string(), "-0.67");
+        assert_eq!(d("1").div_to_scale(&d("-8"), 2, RoundingMode::HalfEven).unwrap().to_string(), "-0.12");
+        assert_eq!(d("1").div_to_scale(&d("-8"), 2, RoundingMode::HalfAwayFromZero).unwrap().to_string(), "-0.13");
+        assert_eq!(one.div_to_scale(&Decimal::zero(), 2, RoundingMode::HalfEven), None);
+        assert_eq!(d("2.345").rescale(2, RoundingMode::HalfEven).to_string(), "2.34");
+        assert_eq!(d("2.355").rescale(2, RoundingMode::HalfEven).to_string(), "2.36");
+        assert_eq!(d("2.345").rescale(2, RoundingMode::HalfUp).to_string(), "2.35");
+        assert_eq!(d("2.5").rescale(4, RoundingMode::HalfUp).to_string(), "2.5000");
+    }
+
+    #[test]
+    fn test_fraction_conversion() {
+        assert_eq!(d("-1.25").to_fraction::<i32>(), Some(Fraction::new(-5, 4)));
+        assert_eq!(Decimal::from_fraction_exact(&Fraction::new(3i64, 8)).unwrap().to_string(), "0.375");
+        assert_eq!(Decimal::from_fraction_exact(&Fraction::new(1i64, 3)), None);
+        assert_eq!(Decimal::from_fraction(&Fraction::new(2i64, 3), 3, RoundingMode::HalfEven).to_string(), "0.667");
+        assert_eq!(Decimal::try_from(Fraction::new(-7i8, 20)).unwrap().to_string(), "-0.35");
+        assert_eq!(Decimal::try_from(Fraction::new(1u8, 3)), Err(NonTerminatingError));
+        assert_eq!(NonTerminatingError.to_string(), "fraction has no finite decimal expansion");
+        assert_eq!(d("123456789012345678901234567890").to_fraction::<i64>(), None);
+    }
+}
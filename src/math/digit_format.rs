@@ -0,0 +1,191 @@
+use crate::math::alphabet::{CharTable, DigitAlphabet};
+use crate::math::digits::{Digits, DigitsError};
+
+///what `Digits::parse` may skip or read besides the digits themselves
+#[derive(Clone, Default)]
+pub struct ParseOptions {
+    prefixes: bool,
+    separators: Vec<char>,
+    trim_whitespace: bool,
+}
+impl ParseOptions {
+    ///only bare digits, like `Digits::from_string_with`
+    pub fn new() -> ParseOptions {
+        ParseOptions::default()
+    }
+    ///skip 0x, 0o or 0b (any case) when it names the base being parsed, digits after 0x are
+    ///read as hex in either case. a prefix for another base is left as digits, "0b1" in base 16 is 0xb1
+    pub fn prefixes(mut self) -> ParseOptions {
+        self.prefixes = true;
+        self
+    }
+    ///chars to drop between digits, such as "_,"
+    pub fn separators(mut self, separators: &str) -> ParseOptions {
+        self.separators = separators.chars().collect();
+        self
+    }
+    ///whitespace around the number
+    pub fn trim_whitespace(mut self) -> ParseOptions {
+        self.trim_whitespace = true;
+        self
+    }
+    ///prefixes, whitespace and the separators `_ , '` plus spaces. '.' is left out, "1.5" must not read as 15
+    pub fn lenient() -> ParseOptions {
+        ParseOptions::new().prefixes().separators("_,' \u{a0}\u{202f}").trim_whitespace()
+    }
+    ///prefixes, whitespace, `_` and the group separator of a locale as in `Grouping::for_locale`,
+    ///so "1.234.567" reads in "de"; `None` when the locale is unknown
+    pub fn for_locale(locale: &str) -> Option<ParseOptions> {
+        let separator = Grouping::for_locale(locale)?.separator;
+        Some(ParseOptions::new().prefixes().separators(&format!("_{separator}")).trim_whitespace())
+    }
+}
+
+///digits split into groups from the lowest end: the first group has `first` digits, every later one `rest`
+#[derive(Copy, Clone)]
+pub struct Grouping {
+    first: usize,
+    rest: usize,
+    separator: char,
+}
+impl Grouping {
+    pub fn new(first: usize, rest: usize, separator: char) -> Grouping {
+        if(first == 0 || rest == 0){
+            panic!("Group size must be positive.");
+        }
+        Grouping { first, rest, separator }
+    }
+    ///1,234,567
+    pub fn thousands(separator: char) -> Grouping {
+        Grouping::new(3, 3, separator)
+    }
+    ///lakh and crore: 12,34,567
+    pub fn indian(separator: char) -> Grouping {
+        Grouping::new(3, 2, separator)
+    }
+    ///myriads, as read in Chinese, Japanese and Korean: 123,4567
+    pub fn east_asian(separator: char) -> Grouping {
+        Grouping::new(4, 4, separator)
+    }
+    ///hex digits in pairs, one group per byte: 1 23 45
+    pub fn bytes(separator: char) -> Grouping {
+        Grouping::new(2, 2, separator)
+    }
+    ///grouping of decimal numbers in a BCP 47 locale such as "en-US", "de" or "hi-IN"; `None` when unknown
+    pub fn for_locale(locale: &str) -> Option<Grouping> {
+        let mut parts = locale.split(['-', '_']);
+        let language = parts.next()?.to_ascii_lowercase();
+        let region = parts.next().map(|r| r.to_ascii_uppercase());
+        if(region.as_deref() == Some("IN")){
+            return Some(Grouping::indian(','));
+        }
+        if(region.as_deref() == Some("CH")){
+            return Some(Grouping::thousands('\''));
+        }
+        Some(match language.as_str() {
+            "en" | "zh" | "ja" | "ko" | "th" | "he" => Grouping::thousands(','),
+            "hi" | "bn" | "mr" | "ta" | "te" => Grouping::indian(','),
+            "de" | "es" | "it" | "nl" | "pt" | "id" | "da" | "tr" => Grouping::thousands('.'),
+            "fr" => Grouping::thousands('\u{202f}'),
+            "ru" | "pl" | "cs" | "sv" | "fi" | "nb" | "uk" => Grouping::thousands('\u{a0}'),
+            _ => return None,
+        })
+    }
+}
+
+impl Digits {
+    ///`from_string_with` after stripping what the options allow
+    pub fn parse(digits_string: &str, max_digit: u8, options: &ParseOptions, alphabet: &impl DigitAlphabet) -> Result<Digits, DigitsError> {
+        let mut text = digits_string;
+        if(options.trim_whitespace){
+            text = text.trim();
+        }
+        let mut hex_prefix = false;
+        if(options.prefixes && text.len() >= 2 && text.starts_with('0')){
+            let prefixed = match text.as_bytes()[1].to_ascii_lowercase() {
+                b'x' => Some(16),
+                b'o' => Some(8),
+                b'b' => Some(2),
+                _ => None,
+            };
+            if(prefixed == Some(max_digit)){
+                hex_prefix = max_digit == 16;
+                text = &text[2..];
+            }
+        }
+        let cleaned: String = text.chars().filter(|c| !options.separators.contains(c)).collect();
+        if(cleaned.is_empty()){
+            return Err(DigitsError::Empty);
+        }
+        if(hex_prefix){
+            return Digits::from_string_with(&cleaned, max_digit, &CharTable::hex());
+        }
+        Digits::from_string_with(&cleaned, max_digit, alphabet)
+    }
+
+    ///digits with a separator between groups, highest digit first
+    pub fn to_string_grouped(&self, grouping: &Grouping, alphabet: &impl DigitAlphabet) -> Result<String, DigitsError> {
+        let mut tokens = Vec::with_capacity(self.len() * 2);
+        for (i, digit) in self.get_u8_array().iter().enumerate() {
+            if(i == grouping.first || (i > grouping.first && (i - grouping.first).is_multiple_of(grouping.rest))){
+                tokens.push(grouping.separator.to_string());
+            }
+            tokens.push(alphabet.encode(*digit).ok_or(DigitsError::NoConversionNumToCharRule(*digit))?);
+        }
+        Ok(tokens.iter().rev().map(String::as_str).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::alphabet::Arabic;
+
+    #[test]
+    fn test_parse_options() {
+        let lenient = ParseOptions::lenient();
+        let value = |s: &str| Digits::parse(s, 10, &lenient, &CharTable::hex()).map(|d| d.to_u64());
+        assert_eq!(value("1_000_000"), Ok(Some(1_000_000)));
+        assert_eq!(value(" 1,234 "), Ok(Some(1234)));
+        assert_eq!(value("0"), Ok(Some(0)));
+        //a prefix is skipped only when it names the requested base
+        let in_base = |s: &str, base: u8| Digits::parse(s, base, &lenient, &Arabic).map(|d| d.to_u64());
+        assert_eq!(in_base("0xFF", 16), Ok(Some(255)));
+        assert_eq!(in_base("0xff", 16), Ok(Some(255)));
+        assert_eq!(in_base("0o17", 8), Ok(Some(15)));
+        assert_eq!(in_base("0B1010", 2), Ok(Some(10)));
+        assert_eq!(in_base("0b1", 16), Ok(Some(0xb1)));
+        assert!(in_base("0xFF", 10).is_err());
+        assert!(in_base("0b1", 8).is_err());
+        assert!(in_base("0x", 16).is_err());
+        assert!(value("12a").is_err());
+        assert!(Digits::parse("1.5", 10, &lenient, &Arabic).is_err());
+        let german = ParseOptions::for_locale("de-DE").unwrap();
+        assert_eq!(Digits::parse("1.234.567", 10, &german, &Arabic).unwrap().to_u64(), Some(1234567));
+        assert!(Digits::parse("1,5", 10, &german, &Arabic).is_err());
+        assert!(ParseOptions::for_locale("xx").is_none());
+        let strict = ParseOptions::new();
+        assert!(Digits::parse("1_000", 10, &strict, &Arabic).is_err());
+        assert!(Digits::parse(" 1", 10, &strict, &Arabic).is_err());
+        assert_eq!(Digits::parse("1_000", 10, &ParseOptions::new().separators("_"), &Arabic).unwrap().to_u64(), Some(1000));
+    }
+
+    #[test]
+    fn test_grouping() {
+        let digits = Digits::from_u64(1234567, 10);
+        let grouped = |g: Grouping| digits.to_string_grouped(&g, &Arabic).unwrap();
+        assert_eq!(grouped(Grouping::thousands(',')), "1,234,567");
+        assert_eq!(grouped(Grouping::indian(',')), "12,34,567");
+        assert_eq!(grouped(Grouping::east_asian(',')), "123,4567");
+        assert_eq!(Digits::from_u64(12345678, 10).to_string_grouped(&Grouping::east_asian(','), &Arabic).unwrap(), "1234,5678");
+        assert_eq!(Digits::from_u64(0x12345, 16).to_string_grouped(&Grouping::bytes(' '), &Arabic).unwrap(), "1 23 45");
+        assert_eq!(Digits::from_u64(999, 10).to_string_grouped(&Grouping::thousands(','), &Arabic).unwrap(), "999");
+        assert_eq!(grouped(Grouping::for_locale("de-DE").unwrap()), "1.234.567");
+        assert_eq!(grouped(Grouping::for_locale("en-IN").unwrap()), "12,34,567");
+        assert_eq!(grouped(Grouping::for_locale("fr").unwrap()), "1\u{202f}234\u{202f}567");
+        assert!(Grouping::for_locale("xx").is_none());
+        //grouped text reads back with the same separator
+        let text = grouped(Grouping::indian(','));
+        assert_eq!(Digits::parse(&text, 10, &ParseOptions::new().separators(","), &Arabic).unwrap().to_u64(), Some(1234567));
+    }
+}
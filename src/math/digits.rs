@@ -1,11 +1,24 @@
-use std::fmt::{Debug, Formatter};
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
 use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+use crate::math::alphabet::{Arabic, DigitAlphabet, FnAlphabet};
 use crate::math::digits::DigitsError::{NoConversionCharToNumRule, NoConversionNumToCharRule};
 
+#[derive(PartialEq, Eq)]
 pub enum DigitsError{
     DigitExceedLimit(u8,u8),
     NoConversionCharToNumRule(char),
     NoConversionNumToCharRule(u8),
+    BaseMismatch(u8,u8),
+    Empty,
+    Negative,
+    Overflow,
+    PlaceExceedBase(u128,u32),
+    PlaceCountMismatch(usize,usize),
+    ChecksumMismatch(u8,u8),
+    UnsupportedBase(u8),
 }
 impl Debug for DigitsError{
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -19,6 +32,30 @@ impl Debug for DigitsError{
             NoConversionNumToCharRule(n) => {
                 write!(f, "no conversion rule for num [{n}] to char")
             }
+            DigitsError::BaseMismatch(a,b) => {
+                write!(f, "digits in base {a} cannot be combined with base {b}")
+            }
+            DigitsError::Empty => {
+                write!(f, "no digits to read")
+            }
+            DigitsError::Negative => {
+                write!(f, "digits cannot hold a negative value")
+            }
+            DigitsError::Overflow => {
+                write!(f, "value does not fit")
+            }
+            DigitsError::PlaceExceedBase(d,base) => {
+                write!(f, "digit {d} does not fit a place in base {base}")
+            }
+            DigitsError::PlaceCountMismatch(got,expected) => {
+                write!(f, "{got} places given where {expected} are expected")
+            }
+            DigitsError::ChecksumMismatch(expected,found) => {
+                write!(f, "check value should be {expected} but is {found}")
+            }
+            DigitsError::UnsupportedBase(base) => {
+                write!(f, "base {base} is not supported")
+            }
         }
     }
 }
@@ -44,6 +81,7 @@ pub const fn char_to_arabic_num(digit:char) ->Option<u8>{
         None
     }
 }
+#[derive(Clone)]
 pub struct Digits{
     digits: Vec<u8>,
     max_digit: u8,
@@ -55,6 +93,17 @@ impl Digits{
             max_digit,
         }
     }
+    ///takes little-endian digits as they are, checking each against the limit
+    pub fn from_u8_array(digits: Vec<u8>, max_digit: u8) -> Result<Digits,DigitsError>{
+        if let Some(d) = digits.iter().find(|d| **d >= max_digit) {
+            return Err(DigitsError::DigitExceedLimit(*d,max_digit));
+        }
+        Ok(Digits{ digits, max_digit })
+    }
+    #[inline]
+    pub fn max_digit(&self) -> u8{
+        self.max_digit
+    }
     #[inline]
     pub fn len(&self) -> usize{
         self.digits.len()
@@ -68,25 +117,36 @@ impl Digits{
         }
     }
     pub fn from_string(digits_string: &str, max_digit: u8, convert_rule: fn(char) ->Option<u8>) -> Result<Digits,DigitsError>{
-        let mut digit=Digits::new(max_digit);
-        let mut digits_chars = digits_string.chars().collect::<Vec<char>>();
-        digits_chars.reverse();
-        for digit_char in digits_chars{
-            digit.append(match convert_rule(digit_char) {
-                Some(n) => n,
-                None => return Err(DigitsError::NoConversionCharToNumRule(digit_char)),
-            })?;
-        }
-        Ok(digit)
+        Self::from_string_with(digits_string, max_digit, &FnAlphabet(|_| None, convert_rule))
     }
+    ///digits through `convert_rule`, leading zeros kept. this shadows `ToString::to_string`,
+    ///the `Display` form is `format!("{d}")` or `ToString::to_string(&d)`
     pub fn to_string(&self,convert_rule: fn(u8)->Option<char>) -> Result<String,DigitsError>{
+        self.to_string_with(&FnAlphabet(convert_rule, |_| None))
+    }
+    ///reads the text token by token, highest digit first
+    pub fn from_string_with(digits_string: &str, max_digit: u8, alphabet: &impl DigitAlphabet) -> Result<Digits,DigitsError>{
+        let mut digits = Vec::new();
+        let mut rest = digits_string;
+        while let Some(c) = rest.chars().next() {
+            let (digit, length) = alphabet.decode(rest).ok_or(NoConversionCharToNumRule(c))?;
+            //a token that consumes nothing, or cuts a char, would never get through the text
+            if(length==0 || !rest.is_char_boundary(length)){
+                return Err(NoConversionCharToNumRule(c));
+            }
+            if(digit>=max_digit){
+                return Err(DigitsError::DigitExceedLimit(digit,max_digit));
+            }
+            digits.push(digit);
+            rest = &rest[length..];
+        }
+        digits.reverse();
+        Ok(Digits{ digits, max_digit })
+    }
+    pub fn to_string_with(&self, alphabet: &impl DigitAlphabet) -> Result<String,DigitsError>{
         let mut result = String::new();
-        let digits_nums =self.digits.iter().rev();
-        for digit in digits_nums {
-            result.push(match convert_rule(*digit) {
-                Some(c) => c,
-                None => return Err(DigitsError::NoConversionNumToCharRule(*digit)),
-            } );
+        for digit in self.digits.iter().rev() {
+            result.push_str(&alphabet.encode(*digit).ok_or(NoConversionNumToCharRule(*digit))?);
         }
         Ok(result)
     }
@@ -102,17 +162,9 @@ impl Digits{
         Some(result)
     }
 
+    ///0-9a-zA-Z, with [n] for larger digits
     pub fn cast_to_string(&self) -> String{
-        fn convert_rule(value: u8) -> Option<String>{
-            match arabic_num_to_char(value) {
-                Some(v)=>Some(v.to_string()),
-                None=> Some(format!("[{}]",value.to_string()))
-            }
-        }
-        match self.to_string_complex(convert_rule){
-            Some(v)=>{v},
-            None=>{panic!("Impossible")}
-        }
+        self.to_string_with(&Arabic).expect("every digit has an arabic token")
     }
     pub fn from_u64(mut value:u64, max_digit:u8) -> Digits{
         let mut digits=Digits::new(max_digit);
@@ -122,11 +174,191 @@ impl Digits{
         }
         digits
     }
+    pub fn from_u128(mut value:u128, max_digit:u8) -> Digits{
+        let mut digits=Digits::new(max_digit);
+        while(value>0){
+            digits.digits.push((value % max_digit as u128) as u8);
+            value/=max_digit as u128;
+        }
+        digits
+    }
+    ///`None` when the value does not fit in u128
+    pub fn to_u128(&self) -> Option<u128>{
+        self.digits.iter().rev().try_fold(0u128, |acc, d| {
+            acc.checked_mul(self.max_digit as u128)?.checked_add(*d as u128)
+        })
+    }
+    ///`None` when the value does not fit in u64
+    pub fn to_u64(&self) -> Option<u64>{
+        self.to_u128().and_then(|v| u64::try_from(v).ok())
+    }
+    ///the same value in another base, for any number of digits. leading zeros are dropped.
+    ///between powers of two (2, 4, 8, 16, 32, 64, 128) the bits are regrouped directly;
+    ///256 itself does not fit in the u8 limit, so bytes are not a base here
+    pub fn convert_base(&self, new_base: u8) -> Digits{
+        if(new_base<2){
+            panic!("Base must be at least 2.");
+        }
+        if(self.max_digit.is_power_of_two() && new_base.is_power_of_two()){
+            return self.regroup_bits(new_base);
+        }
+        //repeated short division of the whole number by the new base, remainders come out lowest first
+        let old_base = self.max_digit as u32;
+        let mut rest: Vec<u8> = self.digits.clone();
+        let mut result = Digits::new(new_base);
+        loop {
+            while(rest.last()==Some(&0)){
+                rest.pop();
+            }
+            if(rest.is_empty()){
+                break;
+            }
+            let mut rem: u32 = 0;
+            for d in rest.iter_mut().rev() {
+                let current = rem * old_base + *d as u32;
+                *d = (current / new_base as u32) as u8;
+                rem = current % new_base as u32;
+            }
+            result.digits.push(rem as u8);
+        }
+        result
+    }
+    fn regroup_bits(&self, new_base: u8) -> Digits{
+        let old_bits = self.max_digit.trailing_zeros();
+        let new_bits = new_base.trailing_zeros();
+        let mut result = Digits::new(new_base);
+        //at most 7 + 7 pending bits
+        let mut buffer: u16 = 0;
+        let mut pending: u32 = 0;
+        for d in &self.digits {
+            buffer |= (*d as u16) << pending;
+            pending += old_bits;
+            while(pending>=new_bits){
+                result.digits.push((buffer & (new_base as u16 - 1)) as u8);
+                buffer >>= new_bits;
+                pending -= new_bits;
+            }
+        }
+        if(pending>0){
+            result.digits.push(buffer as u8);
+        }
+        while(result.digits.last()==Some(&0)){
+            result.digits.pop();
+        }
+        result
+    }
+    ///the digits without leading zeros, the canonical form used by comparison and hashing
+    #[inline]
+    fn significant(&self) -> &[u8]{
+        let end = self.digits.iter().rposition(|d| *d!=0).map_or(0, |i| i+1);
+        &self.digits[..end]
+    }
+    ///drops leading zeros in place, zero becomes no digits at all
+    pub fn trim(&mut self){
+        let end = self.significant().len();
+        self.digits.truncate(end);
+    }
+    pub fn trimmed(&self) -> Digits{
+        Digits{ digits: self.significant().to_vec(), max_digit: self.max_digit }
+    }
+    ///least significant digit first, the storage order
+    pub fn iter(&self) -> std::iter::Copied<std::slice::Iter<'_, u8>>{
+        self.digits.iter().copied()
+    }
+    ///most significant digit first, the reading order
+    pub fn iter_from_highest(&self) -> std::iter::Rev<std::iter::Copied<std::slice::Iter<'_, u8>>>{
+        self.iter().rev()
+    }
+    ///digits least significant first, like `FromIterator` but in any base
+    pub fn from_iter_in_base<I: IntoIterator<Item = u8>>(iter: I, max_digit: u8) -> Result<Digits,DigitsError>{
+        let mut digits = Digits::new(max_digit);
+        for d in iter {
+            digits.append(d)?;
+        }
+        Ok(digits)
+    }
     #[inline]
     pub fn get_u8_array(&self)->&Vec<u8>{
         &self.digits
     }
 }
+///stored digits as written, leading zeros included, and the base
+impl Debug for Digits{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Digits({} base {})", self.cast_to_string(), self.max_digit)
+    }
+}
+///canonical form in `arabic_num_to_char` digits, with [n] past 61 and "0" for zero
+impl Display for Digits{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let text = self.trimmed().cast_to_string();
+        f.pad_integral(true, "", if(text.is_empty()){ "0" }else{ &text })
+    }
+}
+///decimal digits by `char_to_arabic_num`
+impl FromStr for Digits{
+    type Err = DigitsError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if(s.is_empty()){
+            return Err(DigitsError::Empty);
+        }
+        Digits::from_string(s, 10, char_to_arabic_num)
+    }
+}
+///same base and same value, leading zeros do not matter
+impl PartialEq for Digits{
+    fn eq(&self, other: &Self) -> bool {
+        self.max_digit==other.max_digit && self.significant()==other.significant()
+    }
+}
+impl Eq for Digits{}
+impl Hash for Digits{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.max_digit.hash(state);
+        self.significant().hash(state);
+    }
+}
+///by value across bases, equal values then order by base
+impl Ord for Digits{
+    fn cmp(&self, other: &Self) -> Ordering {
+        let by_value = |a: &[u8], b: &[u8]| a.len().cmp(&b.len()).then_with(|| a.iter().rev().cmp(b.iter().rev()));
+        if(self.max_digit==other.max_digit){
+            return by_value(self.significant(), other.significant());
+        }
+        if(self.max_digit<2 || other.max_digit<2){
+            //bases 0 and 1 can only hold zero, so there is nothing to convert
+            return (!self.significant().is_empty()).cmp(&!other.significant().is_empty()).then(self.max_digit.cmp(&other.max_digit));
+        }
+        let other_here = other.convert_base(self.max_digit);
+        by_value(self.significant(), other_here.significant()).then(self.max_digit.cmp(&other.max_digit))
+    }
+}
+impl PartialOrd for Digits{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl<'a> IntoIterator for &'a Digits{
+    type Item = u8;
+    type IntoIter = std::iter::Copied<std::slice::Iter<'a, u8>>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+///decimal digits, least significant first; panics on a digit above 9, see `from_iter_in_base`
+impl FromIterator<u8> for Digits{
+    fn from_iter<I: IntoIterator<Item = u8>>(iter: I) -> Self {
+        Digits::from_iter_in_base(iter, 10).expect("Digit exceeds base 10.")
+    }
+}
+///appends more significant digits; panics on a digit outside the base
+impl Extend<u8> for Digits{
+    fn extend<I: IntoIterator<Item = u8>>(&mut self, iter: I) {
+        for d in iter {
+            self.append(d).expect("Digit exceeds the base.");
+        }
+    }
+}
 impl Index<usize> for Digits{
     type Output = u8;
 
@@ -139,6 +371,202 @@ impl IndexMut<usize> for Digits{
         &mut self.digits[(index)]
     }
 }
+///acc*radix + d, also when only acc*radix leaves the range and d pulls it back in, as at i128::MIN
+fn horner_step(acc: i128, radix: i128, d: i128) -> Option<i128>{
+    acc.checked_mul(radix).and_then(|v| v.checked_add(d)).or_else(|| {
+        let s = acc.signum();
+        (acc-s).checked_mul(radix)?.checked_add(s*radix+d)
+    })
+}
+pub const fn balanced_ternary_to_char(digit:i8) ->Option<char>{
+    match digit {
+        -1 => Some('T'),
+        0 => Some('0'),
+        1 => Some('1'),
+        _ => None,
+    }
+}
+pub const fn char_to_balanced_ternary(digit:char) ->Option<i8>{
+    match digit {
+        'T' => Some(-1),
+        '0' => Some(0),
+        '1' => Some(1),
+        _ => None,
+    }
+}
+///digits of an odd base b running from -(b-1)/2 to (b-1)/2, little-endian like `Digits`.
+///every integer has one such form and negatives need no sign
+pub struct BalancedDigits{
+    digits: Vec<i8>,
+    base: u8,
+}
+impl BalancedDigits{
+    pub fn new(base: u8) -> BalancedDigits{
+        if(base<3 || base.is_multiple_of(2)){
+            panic!("Balanced base must be odd and at least 3.");
+        }
+        BalancedDigits{ digits: Vec::new(), base }
+    }
+    #[inline]
+    pub fn base(&self) -> u8{
+        self.base
+    }
+    #[inline]
+    pub fn len(&self) -> usize{
+        self.digits.len()
+    }
+    #[inline]
+    pub fn get_i8_array(&self) -> &Vec<i8>{
+        &self.digits
+    }
+    pub fn append(&mut self, digit: i8) -> Result<(), DigitsError>{
+        if(digit.unsigned_abs()>self.base/2){
+            Err(DigitsError::DigitExceedLimit(digit.unsigned_abs(),self.base/2+1))
+        }else{
+            self.digits.push(digit);
+            Ok(())
+        }
+    }
+    pub fn from_i128(mut value: i128, base: u8) -> BalancedDigits{
+        let mut digits = BalancedDigits::new(base);
+        let b = base as i128;
+        while(value!=0){
+            //the euclidean remainder, moved into the balanced range by borrowing from the quotient
+            let mut r = value.rem_euclid(b);
+            let mut q = value.div_euclid(b);
+            if(r>(b/2)){
+                r-=b;
+                q+=1;
+            }
+            digits.digits.push(r as i8);
+            value=q;
+        }
+        digits
+    }
+    pub fn from_i64(value: i64, base: u8) -> BalancedDigits{
+        Self::from_i128(value as i128, base)
+    }
+    ///`None` when the value does not fit in i128
+    pub fn to_i128(&self) -> Option<i128>{
+        let radix = self.base as i128;
+        self.digits.iter().rev().try_fold(0i128, |acc, d| horner_step(acc, radix, *d as i128))
+    }
+    ///`None` when the value does not fit in i64
+    pub fn to_i64(&self) -> Option<i64>{
+        self.to_i128().and_then(|v| i64::try_from(v).ok())
+    }
+    pub fn from_string(digits_string: &str, base: u8, convert_rule: fn(char) ->Option<i8>) -> Result<BalancedDigits,DigitsError>{
+        let mut digits = BalancedDigits::new(base);
+        for digit_char in digits_string.chars().rev() {
+            digits.append(match convert_rule(digit_char) {
+                Some(n) => n,
+                None => return Err(DigitsError::NoConversionCharToNumRule(digit_char)),
+            })?;
+        }
+        Ok(digits)
+    }
+    pub fn to_string(&self, convert_rule: fn(i8)->Option<char>) -> Option<String>{
+        self.digits.iter().rev().map(|d| convert_rule(*d)).collect()
+    }
+    ///T01 in base 3; in other bases arabic digits with negative ones as [-n]
+    pub fn cast_to_string(&self) -> String{
+        if(self.base==3){
+            return self.to_string(balanced_ternary_to_char).expect("ternary digits are in range");
+        }
+        self.digits.iter().rev().map(|d| {
+            match arabic_num_to_char(*d as u8) {
+                Some(c) if *d>=0 => c.to_string(),
+                _ => format!("[{d}]"),
+            }
+        }).collect()
+    }
+}
+
+///digits in base -b, each from 0 to b-1 like an ordinary `Digits` of limit b.
+///negabinary is b = 2; as with balanced digits, negatives need no sign
+pub struct NegativeBaseDigits{
+    digits: Digits,
+}
+impl NegativeBaseDigits{
+    ///`base` is the magnitude b of the radix -b
+    pub fn new(base: u8) -> NegativeBaseDigits{
+        if(base<2){
+            panic!("Base must be at least 2.");
+        }
+        NegativeBaseDigits{ digits: Digits::new(base) }
+    }
+    ///wraps digits of limit b as digits in base -b
+    pub fn from_digits(digits: Digits) -> NegativeBaseDigits{
+        if(digits.max_digit<2){
+            panic!("Base must be at least 2.");
+        }
+        NegativeBaseDigits{ digits }
+    }
+    #[inline]
+    pub fn base(&self) -> u8{
+        self.digits.max_digit
+    }
+    #[inline]
+    pub fn digits(&self) -> &Digits{
+        &self.digits
+    }
+    pub fn from_i128(mut value: i128, base: u8) -> NegativeBaseDigits{
+        let mut digits = NegativeBaseDigits::new(base);
+        let b = base as i128;
+        while(value!=0){
+            //value = q*(-b) + r with 0 <= r < b
+            let r = value.rem_euclid(b);
+            digits.digits.digits.push(r as u8);
+            value = -value.div_euclid(b);
+        }
+        digits
+    }
+    pub fn from_i64(value: i64, base: u8) -> NegativeBaseDigits{
+        Self::from_i128(value as i128, base)
+    }
+    ///`None` when the value does not fit in i128
+    pub fn to_i128(&self) -> Option<i128>{
+        let radix = -(self.base() as i128);
+        self.digits.digits.iter().rev().try_fold(0i128, |acc, d| horner_step(acc, radix, *d as i128))
+    }
+    ///`None` when the value does not fit in i64
+    pub fn to_i64(&self) -> Option<i64>{
+        self.to_i128().and_then(|v| i64::try_from(v).ok())
+    }
+    ///digits 0-9a-zA-Z, as for `Digits`
+    pub fn from_string(digits_string: &str, base: u8) -> Result<NegativeBaseDigits,DigitsError>{
+        Ok(NegativeBaseDigits::from_digits(Digits::from_string(digits_string, base, char_to_arabic_num)?))
+    }
+    pub fn cast_to_string(&self) -> String{
+        self.digits.cast_to_string()
+    }
+}
+//primitive integers become decimal digits, use from_u128 for other bases
+macro_rules! impl_digits_from {
+    ($($t:ty),*) => {$(
+        impl From<$t> for Digits{
+            fn from(value: $t) -> Self {
+                Digits::from_u128(value as u128, 10)
+            }
+        }
+    )*};
+}
+impl_digits_from!(u8, u16, u32, u64, u128, usize);
+macro_rules! impl_digits_try_from {
+    ($($t:ty),*) => {$(
+        impl TryFrom<$t> for Digits{
+            type Error = DigitsError;
+            fn try_from(value: $t) -> Result<Self, Self::Error> {
+                if(value<0){
+                    Err(DigitsError::Negative)
+                }else{
+                    Ok(Digits::from_u128(value as u128, 10))
+                }
+            }
+        }
+    )*};
+}
+impl_digits_try_from!(i8, i16, i32, i64, i128, isize);
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -160,4 +588,91 @@ mod tests {
         let digits=Digits::from_u64(num, 10);
         assert_eq!(digits.to_string(arabic_num_to_char).unwrap(),"114514");
     }
+    #[test]
+    fn test_convert_base(){
+        let digits=Digits::from_string("ffffffffffffffffffffffffffffffffff",16,char_to_arabic_num).unwrap();
+        assert_eq!(digits.to_u128(),None);
+        let decimal=digits.convert_base(10);
+        assert_eq!(decimal.cast_to_string(),"87112285931760246646623899502532662132735");
+        assert_eq!(decimal.convert_base(16).cast_to_string(),"f".repeat(34));
+        assert_eq!(digits.convert_base(2).cast_to_string(),"1".repeat(136));
+        assert_eq!(digits.convert_base(8).convert_base(16).cast_to_string(),"f".repeat(34));
+        assert_eq!(Digits::from_u64(255,10).convert_base(128).get_u8_array(),&vec![127,1]);
+        assert_eq!(Digits::from_string("00012",3,char_to_arabic_num).unwrap().convert_base(7).cast_to_string(),"5");
+        assert_eq!(Digits::new(10).convert_base(2).len(),0);
+    }
+    #[test]
+    fn test_primitive_conversions(){
+        assert_eq!(Digits::from_u128(u128::MAX,36).to_u128(),Some(u128::MAX));
+        assert_eq!(Digits::from_u128(u128::MAX,36).to_u64(),None);
+        assert_eq!(Digits::from(2978u16).cast_to_string(),"2978");
+        assert_eq!(Digits::try_from(-5i32).err().map(|e| format!("{e:?}")),Some("digits cannot hold a negative value".to_string()));
+        assert_eq!(Digits::try_from(i64::MAX).unwrap().to_u64(),Some(i64::MAX as u64));
+    }
+    #[test]
+    fn test_balanced(){
+        let digits=BalancedDigits::from_i64(8,3);
+        assert_eq!(digits.get_i8_array(),&vec![-1,0,1]);
+        assert_eq!(digits.cast_to_string(),"10T");
+        assert_eq!(BalancedDigits::from_i64(-8,3).cast_to_string(),"T01");
+        assert_eq!(BalancedDigits::from_string("T01",3,char_to_balanced_ternary).unwrap().to_i64(),Some(-8));
+        assert!(BalancedDigits::from_string("T02",3,char_to_balanced_ternary).is_err());
+        for v in [0,1,-1,i128::MAX,i128::MIN,123456789] {
+            assert_eq!(BalancedDigits::from_i128(v,3).to_i128(),Some(v));
+            assert_eq!(BalancedDigits::from_i128(v,9).to_i128(),Some(v));
+        }
+        assert_eq!(BalancedDigits::from_i64(-7,5).cast_to_string(),"[-1][-2]");
+        assert_eq!(BalancedDigits::from_i128(i128::MAX,3).to_i64(),None);
+    }
+    #[test]
+    fn test_negative_base(){
+        assert_eq!(NegativeBaseDigits::from_i64(6,2).cast_to_string(),"11010");
+        assert_eq!(NegativeBaseDigits::from_i64(-3,2).cast_to_string(),"1101");
+        assert_eq!(NegativeBaseDigits::from_string("11010",2).unwrap().to_i64(),Some(6));
+        assert_eq!(NegativeBaseDigits::from_i64(-15,10).cast_to_string(),"25");
+        for v in [0,1,-1,i128::MAX,i128::MIN,-123456789] {
+            assert_eq!(NegativeBaseDigits::from_i128(v,2).to_i128(),Some(v));
+            assert_eq!(NegativeBaseDigits::from_i128(v,7).to_i128(),Some(v));
+        }
+        assert!(NegativeBaseDigits::from_string("12",2).is_err());
+    }
+    #[test]
+    fn test_value_semantics(){
+        let a: Digits = "007".parse().unwrap();
+        let b = Digits::from_u64(7,10);
+        assert_eq!(a,b);
+        assert_eq!(a.len(),3);
+        assert_eq!(a.trimmed().len(),1);
+        assert_eq!(a.to_string(arabic_num_to_char).unwrap(),"007");
+        assert_eq!(format!("{a}"),"7");
+        assert_eq!(format!("{:?}",a),"Digits(007 base 10)");
+        assert_eq!(format!("{}",Digits::new(2)),"0");
+        assert_ne!(Digits::from_u64(7,10),Digits::from_u64(7,8));
+        assert!(Digits::from_u64(9,10) < Digits::from_u64(10,10));
+        assert!(Digits::from_u64(9,10) > Digits::from_u64(8,16));
+        assert!(Digits::from_u64(16,10) < Digits::from_u64(16,16));
+        let mut map = std::collections::HashMap::new();
+        map.insert(a.clone(),"seven");
+        assert_eq!(map.get(&b),Some(&"seven"));
+        assert!("".parse::<Digits>().is_err());
+        let s: String = ToString::to_string(&a);
+        assert_eq!(s,"7");
+        //bases below 2 hold only zero and still compare
+        assert!(Digits::new(1) < Digits::from_u64(1,10));
+        assert!(Digits::new(0) < Digits::new(10));
+        assert!(Digits::from_u64(0,10) < Digits::new(1).max(Digits::new(16)));
+    }
+    #[test]
+    fn test_iterators(){
+        let digits = Digits::from_u64(123,10);
+        assert_eq!(digits.iter().collect::<Vec<_>>(),[3,2,1]);
+        assert_eq!(digits.iter_from_highest().collect::<Vec<_>>(),[1,2,3]);
+        assert_eq!((&digits).into_iter().sum::<u8>(),6);
+        assert_eq!(digits.iter().collect::<Digits>(),digits);
+        assert_eq!(Digits::from_iter_in_base([1,0,1],2).unwrap().to_u64(),Some(5));
+        assert!(Digits::from_iter_in_base([2],2).is_err());
+        let mut more = Digits::new(10);
+        more.extend([4,5]);
+        assert_eq!(more.to_u64(),Some(54));
+    }
 }
@@ -0,0 +1,169 @@
+use std::fmt::{Display, Formatter};
+use crate::math::digits::{Digits, DigitsError};
+use crate::math::fraction::Fraction;
+use crate::math::traits::Integer;
+
+///a number written out in a positional base as integer.pre(period).
+///the digits after the point are stored like an integer, so index 0 is the last digit
+///and `to_string` prints them in reading order
+pub struct PositionalExpansion {
+    negative: bool,
+    integer: Digits,
+    pre_period: Digits,
+    period: Digits,
+}
+impl PositionalExpansion {
+    pub fn new(negative: bool, integer: Digits, pre_period: Digits, period: Digits) -> Result<Self, DigitsError> {
+        let base = integer.max_digit();
+        for part in [&pre_period, &period] {
+            if(part.max_digit() != base){
+                return Err(DigitsError::BaseMismatch(base, part.max_digit()));
+            }
+        }
+        Ok(PositionalExpansion { negative, integer, pre_period, period })
+    }
+    pub fn base(&self) -> u8 {
+        self.integer.max_digit()
+    }
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+    pub fn integer(&self) -> &Digits {
+        &self.integer
+    }
+    pub fn pre_period(&self) -> &Digits {
+        &self.pre_period
+    }
+    pub fn period(&self) -> &Digits {
+        &self.period
+    }
+
+    ///exact value, `None` when it or base^(period length) does not fit in T
+    pub fn to_fraction<T: Integer>(&self) -> Option<Fraction<T>> {
+        let base = T::from_i128(self.base() as i128)?;
+        let integer: T = digits_value(&self.integer, base)?;
+        let pre: T = digits_value(&self.pre_period, base)?;
+        let shift = checked_power(base, self.pre_period.len())?;
+        let mut value = Fraction::checked_new(pre, shift)?;
+        if(self.period.len() > 0){
+            //0.(R) in base b is R / (b^r - 1)
+            let nines = checked_power(base, self.period.len())?.checked_sub(T::ONE)?;
+            let repeating = Fraction::checked_new(digits_value(&self.period, base)?, nines)?;
+            value = value.checked_add(&repeating.checked_div(&Fraction::from_integer(shift))?)?;
+        }
+        value = value.checked_add(&Fraction::from_integer(integer))?;
+        if(self.negative){ value.checked_neg() }else{ Some(value) }
+    }
+}
+fn digits_value<T: Integer>(digits: &Digits, base: T) -> Option<T> {
+    digits.get_u8_array().iter().rev().try_fold(T::ZERO, |acc, d| {
+        acc.checked_mul(base)?.checked_add(T::from_i128(*d as i128)?)
+    })
+}
+fn checked_power<T: Integer>(base: T, exp: usize) -> Option<T> {
+    (0..exp).try_fold(T::ONE, |acc, _| acc.checked_mul(base))
+}
+///reading-order digits into a Digits, which keeps the last digit first
+fn digits_from_reading_order(digits: &[u8], base: u8) -> Digits {
+    let mut result = Digits::new(base);
+    for d in digits.iter().rev() {
+        result.append(*d).expect("digit is below the base");
+    }
+    result
+}
+impl Display for PositionalExpansion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut text = String::new();
+        if(self.negative){
+            text.push('-');
+        }
+        if(self.integer.len() == 0){
+            text.push('0');
+        }else{
+            text.push_str(&self.integer.cast_to_string());
+        }
+        if(self.pre_period.len() > 0 || self.period.len() > 0){
+            text.push('.');
+            text.push_str(&self.pre_period.cast_to_string());
+        }
+        if(self.period.len() > 0){
+            text.push('(');
+            text.push_str(&self.period.cast_to_string());
+            text.push(')');
+        }
+        f.pad(&text)
+    }
+}
+
+impl<T: Integer> Fraction<T> {
+    ///the exact expansion in `base` (2..=62), with the repeating part found from the remainders
+    pub fn to_expansion(self, base: u8) -> Result<PositionalExpansion, DigitsError> {
+        let (pre, period) = self.expansion_digits(base)?;
+        let b = T::from_i128(base as i128).expect("every integer type holds 2..=62");
+        let mut integer = Digits::new(base);
+        //remainders carry the sign, so the magnitude is read without abs overflowing
+        let mut rest = self.to_integer();
+        while rest != T::ZERO {
+            let d = (rest % b).abs().to_i128().expect("remainder is below the base") as u8;
+            integer.append(d).expect("digit is below the base");
+            rest = rest / b;
+        }
+        Ok(PositionalExpansion {
+            negative: self.numerator().is_negative(),
+            integer,
+            pre_period: digits_from_reading_order(&pre, base),
+            period: digits_from_reading_order(&period, base),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::digits::char_to_arabic_num;
+
+    #[test]
+    fn test_base3() {
+        //31/78 = 0.1(012) in base 3
+        let e = Fraction::new(31i64, 78).to_expansion(3).unwrap();
+        assert_eq!(e.to_string(), "0.1(012)");
+        assert_eq!(e.to_fraction::<i64>(), Some(Fraction::new(31, 78)));
+        assert_eq!(Fraction::new(5i64, 12).to_expansion(3).unwrap().to_string(), "0.1(02)");
+    }
+
+    #[test]
+    fn test_other_bases() {
+        assert_eq!(Fraction::new(1i64, 10).to_expansion(2).unwrap().to_string(), "0.0(0011)");
+        assert_eq!(Fraction::new(-22i64, 7).to_expansion(10).unwrap().to_string(), "-3.(142857)");
+        assert_eq!(Fraction::new(255i64, 16).to_expansion(16).unwrap().to_string(), "f.f");
+        assert_eq!(Fraction::new(3843i64, 62).to_expansion(62).unwrap().to_string(), "Z.Z");
+        assert_eq!(Fraction::from(8i8).to_expansion(2).unwrap().to_string(), "1000");
+        assert_eq!(Fraction::from_integer(i8::MIN).to_expansion(2).unwrap().to_string(), "-10000000");
+        assert_eq!(Fraction::<u8>::zero().to_expansion(7).unwrap().to_string(), "0");
+        assert_eq!(Fraction::new(1i8, 2).expansion_digits(200), Err(DigitsError::UnsupportedBase(200)));
+        assert!(Fraction::new(1i8, 2).to_expansion(63).is_err());
+        assert!(Fraction::new(1i8, 2).to_expansion(1).is_err());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for (p, q) in [(1i64, 3), (-7, 12), (123, 7), (5, 32), (40, 1)] {
+            let f = Fraction::new(p, q);
+            for base in [2u8, 3, 7, 10, 16, 36, 62] {
+                assert_eq!(f.to_expansion(base).unwrap().to_fraction::<i64>(), Some(f), "{f} in base {base}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_parts() {
+        let digits = |s: &str, base: u8| Digits::from_string(s, base, char_to_arabic_num).unwrap();
+        let e = PositionalExpansion::new(false, digits("1", 3), digits("1", 3), digits("012", 3)).unwrap();
+        assert_eq!(e.to_fraction::<i32>(), Some(Fraction::new(109, 78)));
+        let e = PositionalExpansion::new(true, digits("", 10), digits("0", 10), digits("9", 10)).unwrap();
+        assert_eq!(e.to_fraction::<i32>(), Some(Fraction::new(-1, 10)));
+        assert!(PositionalExpansion::new(false, digits("1", 3), digits("1", 10), digits("", 3)).is_err());
+        let e = PositionalExpansion::new(false, digits("ff", 16), digits("", 16), digits("", 16)).unwrap();
+        assert_eq!(e.to_fraction::<i8>(), None);
+    }
+}
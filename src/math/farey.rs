@@ -0,0 +1,216 @@
+use std::cmp::Ordering;
+use crate::math::fraction::Fraction;
+use crate::math::traits::Integer;
+
+///Farey sequence F_n: every reduced fraction in [0, 1] with denominator at most n, in increasing order.
+///every step stays below 2n, so n is limited to half the range of T and the sequence never ends early
+pub struct Farey<T> {
+    n: T,
+    //current and next term a/b < c/d
+    a: T,
+    b: T,
+    c: T,
+    d: T,
+    done: bool,
+}
+impl<T: Integer> Iterator for Farey<T> {
+    type Item = Fraction<T>;
+
+    fn next(&mut self) -> Option<Fraction<T>> {
+        if(self.done){
+            return None;
+        }
+        let current = Fraction::new(self.a, self.b);
+        if(self.c > self.n){
+            //c/d is past 1/1
+            self.done = true;
+            return Some(current);
+        }
+        //next term after a/b, c/d is (k*c - a)/(k*d - b) with k = (n + b) / d.
+        //k*c <= k*d <= n + b <= 2n, checked to fit when the sequence was made
+        let k = (self.n + self.b) / self.d;
+        let (e, f) = (k * self.c - self.a, k * self.d - self.b);
+        (self.a, self.b, self.c, self.d) = (self.c, self.d, e, f);
+        Some(current)
+    }
+}
+///panics when n is not positive or above half the range of T, see `checked_farey`
+pub fn farey<T: Integer>(n: T) -> Farey<T> {
+    if(n < T::ONE){
+        panic!("Farey order must be positive.");
+    }
+    checked_farey(n).expect("Farey order must be at most half the range of the integer type.")
+}
+///`None` when n is not positive or 2n does not fit in T
+pub fn checked_farey<T: Integer>(n: T) -> Option<Farey<T>> {
+    if(n < T::ONE){
+        return None;
+    }
+    n.checked_add(n)?;
+    Some(Farey { n, a: T::ZERO, b: T::ONE, c: T::ONE, d: n, done: false })
+}
+
+///bounds of the Stern–Brocot descent towards a target, as raw (p, q) so 1/0 is allowed
+#[derive(Copy, Clone)]
+struct Bounds<T> {
+    left: (T, T),
+    right: (T, T),
+}
+impl<T: Integer> Bounds<T> {
+    fn root() -> Self {
+        Bounds { left: (T::ZERO, T::ONE), right: (T::ONE, T::ZERO) }
+    }
+    fn mediant(&self) -> Option<(T, T)> {
+        Some((self.left.0.checked_add(self.right.0)?, self.left.1.checked_add(self.right.1)?))
+    }
+}
+
+impl<T: Integer> Fraction<T> {
+    ///(a+c)/(b+d), reduced, `None` on overflow
+    pub fn mediant(&self, other: &Self) -> Option<Self> {
+        Fraction::checked_new(
+            self.numerator().checked_add(other.numerator())?,
+            self.denominator().checked_add(other.denominator())?,
+        )
+    }
+
+    ///path from the root 1/1 of the Stern–Brocot tree, 'L' towards smaller and 'R' towards larger
+    pub fn stern_brocot_path(&self) -> String {
+        if(self.numerator() <= T::ZERO){
+            panic!("Only positive fractions are in the Stern-Brocot tree.");
+        }
+        //[a0; a1, ..., an] is R^a0 L^a1 R^a2 ... with the last run one shorter
+        let terms = self.to_continued_fraction();
+        let mut path = String::new();
+        for (i, a) in terms.iter().enumerate() {
+            let mut run = *a;
+            if(i == terms.len() - 1){
+                run = run - T::ONE;
+            }
+            let step = if(i % 2 == 0){ 'R' }else{ 'L' };
+            while run > T::ZERO {
+                path.push(step);
+                run = run - T::ONE;
+            }
+        }
+        path
+    }
+
+    ///inverse of `stern_brocot_path`, `None` on any char other than 'L'/'R' or on overflow
+    pub fn from_stern_brocot_path(path: &str) -> Option<Self> {
+        let mut bounds = Bounds::root();
+        let mut node = (T::ONE, T::ONE);
+        for step in path.chars() {
+            match step {
+                'L' => bounds.right = node,
+                'R' => bounds.left = node,
+                _ => return None,
+            }
+            node = bounds.mediant()?;
+        }
+        Fraction::checked_new(node.0, node.1)
+    }
+}
+
+///the fractions just before and after `x` in F_n, `x` itself excluded.
+///x does not have to be in F_n, then these are the two members of F_n around it
+pub fn farey_neighbors<T: Integer>(x: &Fraction<T>, n: T) -> (Option<Fraction<T>>, Option<Fraction<T>>) {
+    if(x.numerator().is_negative() || x.numerator() > x.denominator()){
+        panic!("Fraction must lie in [0, 1].");
+    }
+    if(n < T::ONE){
+        panic!("Farey order must be positive.");
+    }
+    let (p, q) = (x.numerator(), x.denominator());
+    let mut bounds = Bounds { left: (T::ZERO, T::ONE), right: (T::ONE, T::ONE) };
+    if(p == T::ZERO){
+        return (None, Some(Fraction::new(T::ONE, n)));
+    }
+    if(p == q){
+        return (Some(Fraction::new(n - T::ONE, n)), None);
+    }
+    loop {
+        let m = match bounds.mediant() {
+            Some(m) if m.1 <= n => m,
+            //nothing between the bounds fits in F_n
+            _ => return (Some(Fraction::new(bounds.left.0, bounds.left.1)),
+                         Some(Fraction::new(bounds.right.0, bounds.right.1))),
+        };
+        match T::cmp_mul(p, m.1, m.0, q) {
+            Ordering::Less => bounds.right = m,
+            Ordering::Greater => bounds.left = m,
+            Ordering::Equal => {
+                //x = left ⊕ right, the neighbours are left ⊕ k*x and right ⊕ k*x with the largest k
+                let step = |side: (T, T)| {
+                    let k = (n - side.1) / q;
+                    Fraction::new(side.0 + k * p, side.1 + k * q)
+                };
+                return (Some(step(bounds.left)), Some(step(bounds.right)));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn f(p: i64, q: i64) -> Fraction<i64> {
+        Fraction::new(p, q)
+    }
+
+    #[test]
+    fn test_farey_sequence() {
+        let f5: Vec<_> = farey(5i64).collect();
+        assert_eq!(f5, [
+            f(0, 1), f(1, 5), f(1, 4), f(1, 3), f(2, 5), f(1, 2),
+            f(3, 5), f(2, 3), f(3, 4), f(4, 5), f(1, 1)
+        ]);
+        assert_eq!(farey(1u8).collect::<Vec<_>>(), [Fraction::zero(), Fraction::one()]);
+        //|F_n| = 1 + sum of euler phi
+        assert_eq!(farey(100u32).count(), 3045);
+        assert!(farey(50i32).collect::<Vec<_>>().windows(2).all(|w| w[0] < w[1]));
+        //the largest order that fits still runs to 1/1
+        let last = checked_farey(63i8).unwrap().last();
+        assert_eq!(last, Some(Fraction::one()));
+        assert_eq!(checked_farey(63i8).unwrap().count(), checked_farey(63i64).unwrap().count());
+        assert!(checked_farey(64i8).is_none());
+        assert!(checked_farey(0u8).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "Farey order must be at most half the range of the integer type.")]
+    fn test_farey_too_large() {
+        farey(u8::MAX);
+    }
+
+    #[test]
+    fn test_mediant() {
+        assert_eq!(f(1, 2).mediant(&f(2, 3)), Some(f(3, 5)));
+        assert_eq!(f(1, 3).mediant(&f(1, 1)), Some(f(1, 2)));
+        assert_eq!(Fraction::new(100i8, 1).mediant(&Fraction::new(100, 1)), None);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        assert_eq!(farey_neighbors(&f(1, 3), 5), (Some(f(1, 4)), Some(f(2, 5))));
+        assert_eq!(farey_neighbors(&f(3, 7), 7), (Some(f(2, 5)), Some(f(1, 2))));
+        assert_eq!(farey_neighbors(&f(3, 7), 5), (Some(f(2, 5)), Some(f(1, 2))));
+        assert_eq!(farey_neighbors(&f(0, 1), 5), (None, Some(f(1, 5))));
+        assert_eq!(farey_neighbors(&f(1, 1), 5), (Some(f(4, 5)), None));
+        assert_eq!(farey_neighbors(&f(1, 2), 100), (Some(f(49, 99)), Some(f(50, 99))));
+    }
+
+    #[test]
+    fn test_stern_brocot() {
+        assert_eq!(f(1, 1).stern_brocot_path(), "");
+        assert_eq!(f(3, 5).stern_brocot_path(), "LRL");
+        assert_eq!(f(5, 2).stern_brocot_path(), "RRL");
+        assert_eq!(f(1, 4).stern_brocot_path(), "LLL");
+        assert_eq!(Fraction::from_stern_brocot_path("LRL"), Some(f(3, 5)));
+        assert_eq!(Fraction::from_stern_brocot_path("RRL"), Some(f(5, 2)));
+        assert_eq!(Fraction::<i64>::from_stern_brocot_path("LX"), None);
+        let x = f(355, 113);
+        assert_eq!(Fraction::from_stern_brocot_path(&x.stern_brocot_path()), Some(x));
+    }
+}
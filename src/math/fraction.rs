@@ -1,41 +1,92 @@
-﻿use crate::math::mathtool::gcd_stein;
+﻿#[cfg(feature = "container")]
+use crate::containers::ord_wrap::Ordered;
+use crate::math::digits::DigitsError;
+use crate::math::mathtool::{checked_gcd, checked_pow, cmp_mul_u128, gcd_stein, iroot};
+use crate::math::traits::Integer;
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display, Formatter};
+use std::iter::{Product, Sum};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign};
+use std::str::FromStr;
 
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+//Eq and Hash compare fields, which is fine because every Fraction is kept in lowest terms
+//with a positive denominator. Ord is by value and implemented below.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub struct Fraction<T> {
     p: T,
     q: T,
 }
 
-impl<T:> Fraction<T> {
-    
-}
-impl Fraction<i64> {
-    pub const fn new(numerator: i64, denominator: i64) -> Self {
-        if denominator == 0 {
+impl<T: Integer> Fraction<T> {
+    pub fn new(numerator: T, denominator: T) -> Self {
+        if denominator == T::ZERO {
             panic!("Denominator cannot be zero.");
         }
 
-        let gcd = gcd_stein(numerator.abs(), denominator.abs());
+        let gcd = match checked_gcd(numerator, denominator) {
+            Some(gcd) => gcd,
+            //only 0/MIN and MIN/MIN have a gcd too large for T
+            None => return if numerator == T::ZERO { Self::zero() } else { Self::one() },
+        };
         let mut num = numerator / gcd;
         let mut den = denominator / gcd;
 
-        if den < 0 {
-            num = -num;
-            den = -den;
+        if den.is_negative() {
+            num = T::ZERO - num;
+            den = T::ZERO - den;
         }
 
         Self { p: num, q: den }
     }
 
-    pub fn numerator(&self) -> i64 {
+    /// same as `new`, but gives `None` on a zero denominator or when the sign cannot be normalized
+    pub fn checked_new(numerator: T, denominator: T) -> Option<Self> {
+        if denominator == T::ZERO {
+            return None;
+        }
+        let gcd = match checked_gcd(numerator, denominator) {
+            Some(gcd) => gcd,
+            None => return Some(if numerator == T::ZERO { Self::zero() } else { Self::one() }),
+        };
+        let mut num = numerator / gcd;
+        let mut den = denominator / gcd;
+        if den.is_negative() {
+            num = num.checked_neg()?;
+            den = den.checked_neg()?;
+        }
+        Some(Self { p: num, q: den })
+    }
+
+    /// the fraction `value/1`
+    pub fn from_integer(value: T) -> Self {
+        Self { p: value, q: T::ONE }
+    }
+
+    pub fn zero() -> Self {
+        Self::from_integer(T::ZERO)
+    }
+
+    pub fn one() -> Self {
+        Self::from_integer(T::ONE)
+    }
+
+    pub fn numerator(&self) -> T {
         self.p
     }
 
-    pub fn denominator(&self) -> i64 {
+    pub fn denominator(&self) -> T {
         self.q
     }
 
+    pub fn is_zero(&self) -> bool {
+        self.p == T::ZERO
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.q == T::ONE
+    }
+
     pub fn abs(&self) -> Self {
         Self {
             p: self.p.abs(),
@@ -45,43 +96,1360 @@ impl Fraction<i64> {
 
     pub fn negate(&self) -> Self {
         Self {
-            p: -self.p,
+            p: T::ZERO - self.p,
             q: self.q,
         }
     }
 
+    pub fn recip(&self) -> Self {
+        if self.p == T::ZERO {
+            panic!("Cannot invert zero fraction.");
+        }
+        Self::new(self.q, self.p)
+    }
+
+    #[deprecated(note = "use the + operator or checked_add")]
     pub fn add(&self, other: &Self) -> Self {
-        let num = self.p * other.q + other.p * self.q;
-        let den = self.q * other.q;
-        Self::new(num, den)
+        *self + *other
     }
 
+    #[deprecated(note = "use the - operator or checked_sub")]
     pub fn subtract(&self, other: &Self) -> Self {
-        let num = self.p * other.q - other.p * self.q;
-        let den = self.q * other.q;
-        Self::new(num, den)
+        *self - *other
     }
 
+    #[deprecated(note = "use the * operator or checked_mul")]
     pub fn multiply(&self, other: &Self) -> Self {
-        let num = self.p * other.p;
-        let den = self.q * other.q;
-        Self::new(num, den)
+        *self * *other
     }
 
+    #[deprecated(note = "use the / operator or checked_div")]
     pub fn divide(&self, other: &Self) -> Self {
-        if other.p == 0 {
-            panic!("Cannot divide by zero fraction.");
+        *self / *other
+    }
+
+    #[deprecated(note = "use the % operator or checked_rem")]
+    pub fn remainder(&self, other: &Self) -> Self {
+        *self % *other
+    }
+
+    //all checked operations divide out the common factors before multiplying,
+    //so a representable result is never rejected for an overflowing a*d or b*c
+
+    pub fn checked_neg(&self) -> Option<Self> {
+        Some(Self { p: self.p.checked_neg()?, q: self.q })
+    }
+
+    pub fn checked_recip(&self) -> Option<Self> {
+        Self::one().checked_div(self)
+    }
+
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        let g = gcd_stein(self.q, other.q);
+        let (bg, dg) = (self.q / g, other.q / g);
+        let t = self.p.checked_mul(dg)?.checked_add(other.p.checked_mul(bg)?)?;
+        Self::knuth_reduce(t, g, bg, other.q)
+    }
+
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        let g = gcd_stein(self.q, other.q);
+        let (bg, dg) = (self.q / g, other.q / g);
+        let t = self.p.checked_mul(dg)?.checked_sub(other.p.checked_mul(bg)?)?;
+        Self::knuth_reduce(t, g, bg, other.q)
+    }
+
+    ///reduce t/(b/g*d) where g=gcd(b,d), only a factor of g can be shared with t
+    fn knuth_reduce(t: T, g: T, bg: T, d: T) -> Option<Self> {
+        if t == T::ZERO {
+            return Some(Self::zero());
+        }
+        let g2 = gcd_stein(t, g);
+        Some(Self { p: t / g2, q: bg.checked_mul(d / g2)? })
+    }
+
+    pub fn checked_mul(&self, other: &Self) -> Option<Self> {
+        if self.p == T::ZERO || other.p == T::ZERO {
+            return Some(Self::zero());
+        }
+        let g1 = gcd_stein(self.p, other.q);
+        let g2 = gcd_stein(other.p, self.q);
+        Some(Self {
+            p: (self.p / g1).checked_mul(other.p / g2)?,
+            q: (self.q / g2).checked_mul(other.q / g1)?,
+        })
+    }
+
+    pub fn checked_div(&self, other: &Self) -> Option<Self> {
+        if other.p == T::ZERO {
+            return None;
+        }
+        //the reciprocal is already in lowest terms, only the sign needs moving
+        let recip = if other.p.is_negative() {
+            Self { p: other.q.checked_neg()?, q: other.p.checked_neg()? }
+        } else {
+            Self { p: other.q, q: other.p }
+        };
+        self.checked_mul(&recip)
+    }
+
+    /// remainder of truncated division, carrying the sign of `self` like integer `%`
+    pub fn checked_rem(&self, other: &Self) -> Option<Self> {
+        if other.p == T::ZERO {
+            return None;
         }
-        let num = self.p * other.q;
-        let den = self.q * other.p;
-        Self::new(num, den)
+        let g = gcd_stein(self.q, other.q);
+        let x = self.p.checked_mul(other.q / g)?;
+        let y = other.p.checked_mul(self.q / g)?;
+        Self::checked_new(x.checked_rem(y)?, (self.q / g).checked_mul(other.q)?)
     }
 
-    pub fn to_f64(&self) -> f64 {
-        self.p as f64 / self.q as f64
+    pub fn to_f64(self) -> f64 {
+        self.p.to_f64() / self.q.to_f64()
     }
 
-    pub fn to_i64(&self) -> i64 {
+    /// integer part, truncated toward zero
+    pub fn to_integer(self) -> T {
         self.p / self.q
     }
 }
+impl Fraction<i64> {
+    pub fn to_i64(self) -> i64 {
+        self.to_integer()
+    }
+}
+impl<T: Integer> Ord for Fraction<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        //denominators are positive, so p1/q1 <=> p2/q2 is p1*q2 <=> p2*q1
+        T::cmp_mul(self.p, other.q, other.p, self.q)
+    }
+}
+impl<T: Integer> PartialOrd for Fraction<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl<T: Integer> Default for Fraction<T> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+impl<T: Integer> From<T> for Fraction<T> {
+    fn from(value: T) -> Self {
+        Self::from_integer(value)
+    }
+}
+
+///the operators panic like the integer ones do, on a zero divisor and on overflow
+fn apply_op<T: Integer>(a: &Fraction<T>, b: &Fraction<T>, checked: fn(&Fraction<T>, &Fraction<T>) -> Option<Fraction<T>>, divides: bool, what: &str) -> Fraction<T> {
+    if divides && b.is_zero() {
+        panic!("Cannot divide by zero fraction.");
+    }
+    checked(a, b).unwrap_or_else(|| panic!("attempt to {what} with overflow"))
+}
+macro_rules! impl_fraction_op {
+    ($op:ident, $method:ident, $assign:ident, $assign_method:ident, $checked:ident, $divides:expr, $what:expr) => {
+        impl<T: Integer> $op for Fraction<T> {
+            type Output = Self;
+            fn $method(self, other: Self) -> Self {
+                apply_op(&self, &other, Fraction::$checked, $divides, $what)
+            }
+        }
+        impl<T: Integer> $op<&Fraction<T>> for &Fraction<T> {
+            type Output = Fraction<T>;
+            fn $method(self, other: &Fraction<T>) -> Fraction<T> {
+                apply_op(self, other, Fraction::$checked, $divides, $what)
+            }
+        }
+        impl<T: Integer> $op<&Fraction<T>> for Fraction<T> {
+            type Output = Self;
+            fn $method(self, other: &Fraction<T>) -> Self {
+                apply_op(&self, other, Fraction::$checked, $divides, $what)
+            }
+        }
+        impl<T: Integer> $assign for Fraction<T> {
+            fn $assign_method(&mut self, other: Self) {
+                *self = apply_op(self, &other, Fraction::$checked, $divides, $what);
+            }
+        }
+    };
+}
+impl_fraction_op!(Add, add, AddAssign, add_assign, checked_add, false, "add fractions");
+impl_fraction_op!(Sub, sub, SubAssign, sub_assign, checked_sub, false, "subtract fractions");
+impl_fraction_op!(Mul, mul, MulAssign, mul_assign, checked_mul, false, "multiply fractions");
+impl_fraction_op!(Div, div, DivAssign, div_assign, checked_div, true, "divide fractions");
+impl_fraction_op!(Rem, rem, RemAssign, rem_assign, checked_rem, true, "take remainder of fractions");
+
+impl<T: Integer> Neg for Fraction<T> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        self.checked_neg().expect("attempt to negate fraction with overflow")
+    }
+}
+impl<T: Integer> Sum for Fraction<T> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, x| acc + x)
+    }
+}
+impl<'a, T: Integer> Sum<&'a Fraction<T>> for Fraction<T> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, x| acc + *x)
+    }
+}
+impl<T: Integer> Product for Fraction<T> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), |acc, x| acc * x)
+    }
+}
+impl<'a, T: Integer> Product<&'a Fraction<T>> for Fraction<T> {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), |acc, x| acc * *x)
+    }
+}
+
+
+pub enum ParseFractionError {
+    Empty,
+    ///unexpected char and its byte position in the input
+    InvalidChar(char, usize),
+    UnexpectedEnd,
+    ZeroDenominator,
+    OutOfRange,
+}
+impl Debug for ParseFractionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseFractionError::Empty => write!(f, "cannot parse fraction from empty string"),
+            ParseFractionError::InvalidChar(c, at) => write!(f, "invalid char '{c}' at {at}"),
+            ParseFractionError::UnexpectedEnd => write!(f, "unexpected end of fraction text"),
+            ParseFractionError::ZeroDenominator => write!(f, "denominator cannot be zero"),
+            ParseFractionError::OutOfRange => write!(f, "fraction out of range of the integer type"),
+        }
+    }
+}
+impl Display for ParseFractionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+impl std::error::Error for ParseFractionError {}
+
+///reads the text char by char, keeping byte positions for error reporting
+struct FractionParser<'a> {
+    text: &'a str,
+    pos: usize,
+}
+impl<'a> FractionParser<'a> {
+    fn peek(&self) -> Option<char> {
+        self.text[self.pos..].chars().next()
+    }
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+    fn eat(&mut self, c: char) -> bool {
+        if(self.peek() == Some(c)){
+            self.pos += c.len_utf8();
+            true
+        }else{
+            false
+        }
+    }
+    fn error_here(&self) -> ParseFractionError {
+        match self.peek() {
+            Some(c) => ParseFractionError::InvalidChar(c, self.pos),
+            None => ParseFractionError::UnexpectedEnd,
+        }
+    }
+    fn skip_whitespace(&mut self) -> bool {
+        let start = self.pos;
+        while matches!(self.peek(), Some(c) if c.is_whitespace()) {
+            self.bump();
+        }
+        self.pos != start
+    }
+    ///true for '-', false for '+' or nothing
+    fn sign(&mut self) -> bool {
+        if(self.eat('-')){
+            true
+        }else{
+            self.eat('+');
+            false
+        }
+    }
+    ///a run of decimal digits, possibly empty
+    fn digits(&mut self) -> &'a str {
+        let start = self.pos;
+        while matches!(self.peek(), Some(c) if c.is_ascii_digit()) {
+            self.bump();
+        }
+        &self.text[start..self.pos]
+    }
+    fn nonempty_digits(&mut self) -> Result<&'a str, ParseFractionError> {
+        let digits = self.digits();
+        if(digits.is_empty()){
+            Err(self.error_here())
+        }else{
+            Ok(digits)
+        }
+    }
+    fn finish(&self) -> Result<(), ParseFractionError> {
+        if(self.pos == self.text.len()){ Ok(()) }else{ Err(self.error_here()) }
+    }
+}
+///value of a run of decimal digits, accumulated negatively when `negative` so MIN is reachable
+fn digits_to_integer<T: Integer>(digits: &str, negative: bool) -> Result<T, ParseFractionError> {
+    let ten = T::from_i128(10).ok_or(ParseFractionError::OutOfRange)?;
+    let mut value = T::ZERO;
+    for b in digits.bytes() {
+        let d = T::from_i128((b - b'0') as i128).ok_or(ParseFractionError::OutOfRange)?;
+        value = value.checked_mul(ten).and_then(|v| {
+            if(negative){ v.checked_sub(d) }else{ v.checked_add(d) }
+        }).ok_or(ParseFractionError::OutOfRange)?;
+    }
+    Ok(value)
+}
+fn pow10<T: Integer>(exp: usize) -> Option<T> {
+    let ten = T::from_i128(10)?;
+    (0..exp).try_fold(T::ONE, |acc, _| acc.checked_mul(ten))
+}
+
+impl<T: Integer> FromStr for Fraction<T> {
+    type Err = ParseFractionError;
+
+    ///accepts "3/4", "-7/2", "1 1/2", "0.125", "1.5e-3", "0.(3)" and "0.1(6)"
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = FractionParser { text: s, pos: 0 };
+        parser.skip_whitespace();
+        if(parser.peek().is_none()){
+            return Err(ParseFractionError::Empty);
+        }
+        let negative = parser.sign();
+        let start = parser.pos;
+        let int_part = parser.digits();
+        match parser.peek() {
+            Some('/') => {
+                parser.bump();
+                let num: T = digits_to_integer(int_part, negative)?;
+                if(int_part.is_empty()){
+                    return Err(ParseFractionError::InvalidChar('/', start));
+                }
+                let den_negative = parser.sign();
+                let den: T = digits_to_integer(parser.nonempty_digits()?, den_negative)?;
+                parser.skip_whitespace();
+                parser.finish()?;
+                if(den == T::ZERO){
+                    return Err(ParseFractionError::ZeroDenominator);
+                }
+                Self::checked_new(num, den).ok_or(ParseFractionError::OutOfRange)
+            }
+            Some(c) if c.is_whitespace() && !int_part.is_empty() => {
+                //mixed number, the sign in front applies to the whole value
+                parser.skip_whitespace();
+                if(parser.peek().is_none()){
+                    return Self::from_decimal_parts(int_part, "", "", 0, negative);
+                }
+                let num: T = digits_to_integer(parser.nonempty_digits()?, negative)?;
+                if(!parser.eat('/')){
+                    return Err(parser.error_here());
+                }
+                let den: T = digits_to_integer(parser.nonempty_digits()?, false)?;
+                parser.skip_whitespace();
+                parser.finish()?;
+                if(den == T::ZERO){
+                    return Err(ParseFractionError::ZeroDenominator);
+                }
+                let whole: T = digits_to_integer(int_part, negative)?;
+                Self::from_integer(whole)
+                    .checked_add(&Self::checked_new(num, den).ok_or(ParseFractionError::OutOfRange)?)
+                    .ok_or(ParseFractionError::OutOfRange)
+            }
+            _ => {
+                let mut frac_part = "";
+                let mut repetend = "";
+                if(parser.eat('.')){
+                    frac_part = parser.digits();
+                    if(parser.eat('(')){
+                        repetend = parser.nonempty_digits()?;
+                        if(!parser.eat(')')){
+                            return Err(parser.error_here());
+                        }
+                    }
+                }
+                if(int_part.is_empty() && frac_part.is_empty() && repetend.is_empty()){
+                    return Err(parser.error_here());
+                }
+                let mut exponent: i64 = 0;
+                if(parser.eat('e') || parser.eat('E')){
+                    let exp_negative = parser.sign();
+                    exponent = digits_to_integer(parser.nonempty_digits()?, exp_negative)?;
+                }
+                parser.skip_whitespace();
+                parser.finish()?;
+                Self::from_decimal_parts(int_part, frac_part, repetend, exponent, negative)
+            }
+        }
+    }
+}
+impl<T: Integer> Fraction<T> {
+    ///exact value of `int.frac(repetend) * 10^exponent`
+    fn from_decimal_parts(int_part: &str, frac_part: &str, repetend: &str, exponent: i64, negative: bool)
+                          -> Result<Self, ParseFractionError> {
+        //trailing zeros do not change a terminating decimal but may overflow the integer
+        let frac_part = if(repetend.is_empty()){ frac_part.trim_end_matches('0') }else{ frac_part };
+        let head = format!("{int_part}{frac_part}");
+        let head_value: T = digits_to_integer(&head, false)?;
+        let (num, den) = if(repetend.is_empty()){
+            (head_value, pow10::<T>(frac_part.len()))
+        }else{
+            //x = head.(rep)  =>  x * 10^r - x = head.rep - head
+            let all_value: T = digits_to_integer(&format!("{head}{repetend}"), false)?;
+            let nines = pow10::<T>(repetend.len()).and_then(|v| v.checked_sub(T::ONE));
+            (all_value - head_value,
+             pow10::<T>(frac_part.len()).zip(nines).and_then(|(a, b)| a.checked_mul(b)))
+        };
+        let den = den.ok_or(ParseFractionError::OutOfRange)?;
+        let mut value = Self::checked_new(num, den).ok_or(ParseFractionError::OutOfRange)?;
+        if(!value.is_zero()){
+            let ten = Self::from_integer(T::from_i128(10).ok_or(ParseFractionError::OutOfRange)?);
+            for _ in 0..exponent.unsigned_abs() {
+                value = if(exponent > 0){ value.checked_mul(&ten) }else{ value.checked_div(&ten) }
+                    .ok_or(ParseFractionError::OutOfRange)?;
+            }
+        }
+        if(negative){
+            value = value.checked_neg().ok_or(ParseFractionError::OutOfRange)?;
+        }
+        Ok(value)
+    }
+}
+
+///how a `Fraction` is written out by `Fraction::display`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FractionStyle {
+    ///7/2
+    Improper,
+    ///3 1/2
+    Mixed,
+    ///exact decimal with the cycle in parentheses, 0.1(6)
+    Repeating,
+    ///exact decimal with the cycle overlined, 0.16̅
+    Overline,
+    ///decimal cut after the given number of places
+    Truncated(usize),
+    ///decimal rounded half away from zero to the given number of places
+    Rounded(usize),
+    ///⁷⁄₂
+    Unicode,
+    ///\frac{7}{2}
+    Latex,
+}
+pub struct FractionDisplay<'a, T> {
+    value: &'a Fraction<T>,
+    style: FractionStyle,
+}
+const SUPERSCRIPTS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+const SUBSCRIPTS: [char; 10] = ['₀', '₁', '₂', '₃', '₄', '₅', '₆', '₇', '₈', '₉'];
+
+///next digit in `base` of r/q for 0 <= r < q, updating r to the new remainder.
+///works like base*r but never forms it, so it cannot overflow
+fn next_digit<T: Integer>(r: &mut T, q: T, base: u8) -> u8 {
+    let step = *r;
+    let mut acc = T::ZERO;
+    let mut digit = 0;
+    for _ in 0..base {
+        if(acc >= q - step){
+            acc = acc - (q - step);
+            digit += 1;
+        }else{
+            acc = acc + step;
+        }
+    }
+    *r = acc;
+    digit
+}
+///add one at the last place of the digits, returns whether it carried out of the front
+fn increment_digits(digits: &mut [u8]) -> bool {
+    for d in digits.iter_mut().rev() {
+        if(*d == 9){
+            *d = 0;
+        }else{
+            *d += 1;
+            return false;
+        }
+    }
+    true
+}
+fn increment_decimal_string(text: &str) -> String {
+    let mut digits = text.bytes().map(|b| b - b'0').collect::<Vec<u8>>();
+    let mut result = String::new();
+    if(increment_digits(&mut digits)){
+        result.push('1');
+    }
+    result.extend(digits.iter().map(|d| (b'0' + d) as char));
+    result
+}
+
+impl<T: Integer> Fraction<T> {
+    pub fn display(&self, style: FractionStyle) -> FractionDisplay<'_, T> {
+        FractionDisplay { value: self, style }
+    }
+
+    ///(is negative, |integer part| as text, |remainder|)
+    fn split_integer(&self) -> (bool, String, T) {
+        let int = self.p / self.q;
+        let int_text = int.to_string().trim_start_matches('-').to_string();
+        (self.p.is_negative(), int_text, (self.p % self.q).abs())
+    }
+
+    ///exact decimal digits after the point, split into (pre-period, period)
+    pub fn decimal_expansion(&self) -> (Vec<u8>, Vec<u8>) {
+        self.expansion_digits(10).expect("base 10 is supported")
+    }
+
+    ///exact digits after the point in `base` (2..=62), most significant first, split into (pre-period, period)
+    pub fn expansion_digits(&self, base: u8) -> Result<(Vec<u8>, Vec<u8>), DigitsError> {
+        if(!(2..=62).contains(&base)){
+            return Err(DigitsError::UnsupportedBase(base));
+        }
+        let (_, _, mut r) = self.split_integer();
+        //every step strips the factors q shares with the base, the count is the pre-period length
+        let mut pre_len = 0;
+        let mut rest = self.q;
+        let b = T::from_i128(base as i128).expect("every integer type holds 2..=62");
+        loop {
+            let g = gcd_stein(rest, b);
+            if(g == T::ONE){
+                break;
+            }
+            rest = rest / g;
+            pre_len += 1;
+        }
+        let mut pre = Vec::with_capacity(pre_len);
+        for _ in 0..pre_len {
+            if(r == T::ZERO){
+                break;
+            }
+            pre.push(next_digit(&mut r, self.q, base));
+        }
+        let mut period = Vec::new();
+        if(r != T::ZERO){
+            let start = r;
+            loop {
+                period.push(next_digit(&mut r, self.q, base));
+                if(r == start){
+                    break;
+                }
+            }
+        }
+        Ok((pre, period))
+    }
+
+    fn write_places(&self, f: &mut Formatter<'_>, places: usize, round: bool) -> std::fmt::Result {
+        let (negative, mut int_text, mut r) = self.split_integer();
+        let mut digits = Vec::with_capacity(places);
+        for _ in 0..places {
+            digits.push(next_digit(&mut r, self.q, 10));
+        }
+        if(round && next_digit(&mut r, self.q, 10) >= 5 && increment_digits(&mut digits)){
+            int_text = increment_decimal_string(&int_text);
+        }
+        let is_zero = int_text == "0" && digits.iter().all(|d| *d == 0);
+        let mut text = int_text;
+        if(places > 0){
+            text.push('.');
+            text.extend(digits.iter().map(|d| (b'0' + d) as char));
+        }
+        //the sign goes through pad_integral so + and 0 flags put it in front of the padding
+        f.pad_integral(!negative || is_zero, "", &text)
+    }
+}
+impl<T: Integer> Display for FractionDisplay<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let value = self.value;
+        let text = match self.style {
+            FractionStyle::Improper if value.is_integer() => value.p.to_string(),
+            FractionStyle::Improper => format!("{}/{}", value.p, value.q),
+            FractionStyle::Mixed => {
+                let (negative, int_text, r) = value.split_integer();
+                let sign = if(negative){ "-" }else{ "" };
+                if(r == T::ZERO){
+                    format!("{sign}{int_text}")
+                }else if(int_text == "0"){
+                    format!("{sign}{r}/{}", value.q)
+                }else{
+                    format!("{sign}{int_text} {r}/{}", value.q)
+                }
+            }
+            FractionStyle::Repeating | FractionStyle::Overline => {
+                let (negative, int_text, _) = value.split_integer();
+                let (pre, period) = value.decimal_expansion();
+                let mut text = String::new();
+                if(negative){
+                    text.push('-');
+                }
+                text.push_str(&int_text);
+                if(!pre.is_empty() || !period.is_empty()){
+                    text.push('.');
+                }
+                text.extend(pre.iter().map(|d| (b'0' + d) as char));
+                if(!period.is_empty()){
+                    if(self.style == FractionStyle::Repeating){
+                        text.push('(');
+                        text.extend(period.iter().map(|d| (b'0' + d) as char));
+                        text.push(')');
+                    }else{
+                        for d in period {
+                            text.push((b'0' + d) as char);
+                            text.push('\u{0305}');
+                        }
+                    }
+                }
+                text
+            }
+            FractionStyle::Truncated(places) => return value.write_places(f, places, false),
+            FractionStyle::Rounded(places) => return value.write_places(f, places, true),
+            FractionStyle::Unicode if value.is_integer() => value.p.to_string(),
+            FractionStyle::Unicode => {
+                let mut text = String::new();
+                if(value.p.is_negative()){
+                    text.push('-');
+                }
+                let num = value.p.to_string();
+                text.extend(num.trim_start_matches('-').bytes().map(|b| SUPERSCRIPTS[(b - b'0') as usize]));
+                text.push('⁄');
+                text.extend(value.q.to_string().bytes().map(|b| SUBSCRIPTS[(b - b'0') as usize]));
+                text
+            }
+            FractionStyle::Latex if value.is_integer() => value.p.to_string(),
+            FractionStyle::Latex => {
+                let num = value.p.to_string();
+                let sign = if(value.p.is_negative()){ "-" }else{ "" };
+                format!("{sign}\\frac{{{}}}{{{}}}", num.trim_start_matches('-'), value.q)
+            }
+        };
+        f.pad(&text)
+    }
+}
+///`{}` gives 7/2, `{:#}` gives 3 1/2 and `{:.N}` rounds to N decimal places
+impl<T: Integer> Display for Fraction<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let style = match f.precision() {
+            Some(places) => FractionStyle::Rounded(places),
+            None if f.alternate() => FractionStyle::Mixed,
+            None => FractionStyle::Improper,
+        };
+        Display::fmt(&self.display(style), f)
+    }
+}
+
+pub enum FromFloatError {
+    NotFinite,
+    OutOfRange,
+}
+impl Debug for FromFloatError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FromFloatError::NotFinite => write!(f, "cannot convert NaN or infinity to fraction"),
+            FromFloatError::OutOfRange => write!(f, "float out of range of the fraction type"),
+        }
+    }
+}
+impl Display for FromFloatError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+impl std::error::Error for FromFloatError {}
+
+///|value| as an exact ratio of u128 (numerator, power of two denominator exponent).
+///exponents past 127 cannot be represented, so those tiny values lose their low bits
+fn f64_to_dyadic(value: f64) -> Option<(u128, u32)> {
+    let bits = value.abs().to_bits();
+    let biased = (bits >> 52) as i32;
+    let fraction = bits & ((1 << 52) - 1);
+    let (mut mantissa, mut exp) = if(biased == 0){
+        (fraction as u128, -1074)
+    }else{
+        ((fraction | (1 << 52)) as u128, biased - 1075)
+    };
+    if(mantissa == 0){
+        return Some((0, 0));
+    }
+    let tz = mantissa.trailing_zeros();
+    mantissa >>= tz;
+    exp += tz as i32;
+    if(exp >= 0){
+        //integers, the caller decides whether they fit
+        if(exp as u32 + (128 - mantissa.leading_zeros()) > 127){
+            return None;
+        }
+        Some((mantissa << exp, 0))
+    }else if(-exp <= 127){
+        Some((mantissa, (-exp) as u32))
+    }else{
+        //below 2^-255 every bit is shifted out, and the shift itself would overflow
+        let shift = (-exp - 127) as u32;
+        Some((mantissa.checked_shr(shift).unwrap_or(0), 127))
+    }
+}
+
+impl<T: Integer> Fraction<T> {
+    ///the exact value of a finite float, every f64 is a dyadic rational m/2^k
+    pub fn from_f64_exact(value: f64) -> Result<Self, FromFloatError> {
+        if(!value.is_finite()){
+            return Err(FromFloatError::NotFinite);
+        }
+        let (num, shift) = f64_to_dyadic(value).ok_or(FromFloatError::OutOfRange)?;
+        if(shift > 126){
+            return Err(FromFloatError::OutOfRange);
+        }
+        let num = i128::try_from(num).map_err(|_| FromFloatError::OutOfRange)?;
+        let num = T::from_i128(if(value < 0.0){ -num }else{ num }).ok_or(FromFloatError::OutOfRange)?;
+        let den = T::from_i128(1 << shift).ok_or(FromFloatError::OutOfRange)?;
+        Ok(Self::new(num, den))
+    }
+
+    ///the closest fraction to `value` whose denominator does not exceed `max_denominator`,
+    ///found by walking the continued fraction of the exact float value (Stern–Brocot descent).
+    ///ties go to the smaller denominator
+    pub fn approximate_f64(value: f64, max_denominator: T) -> Result<Self, FromFloatError> {
+        if(!value.is_finite()){
+            return Err(FromFloatError::NotFinite);
+        }
+        if(max_denominator < T::ONE){
+            panic!("Maximum denominator must be positive.");
+        }
+        let limit = max_denominator.to_i128().map_or(i128::MAX as u128, |v| v as u128);
+        let (num, shift) = f64_to_dyadic(value).ok_or(FromFloatError::OutOfRange)?;
+        let (p, q) = best_approximation(num, 1u128 << shift, limit).ok_or(FromFloatError::OutOfRange)?;
+        let p = i128::try_from(p).map_err(|_| FromFloatError::OutOfRange)?;
+        let p = T::from_i128(if(value < 0.0){ -p }else{ p }).ok_or(FromFloatError::OutOfRange)?;
+        let q = T::from_i128(q as i128).ok_or(FromFloatError::OutOfRange)?;
+        Ok(Self { p, q })
+    }
+}
+///best approximation p/q of n/d with q <= limit, `None` if the numerator overflows
+fn best_approximation(mut n: u128, mut d: u128, limit: u128) -> Option<(u128, u128)> {
+    //(p0/q0, p1/q1) are the last two convergents
+    let (mut p0, mut q0, mut p1, mut q1) = (0u128, 1u128, 1u128, 0u128);
+    while d != 0 {
+        let a = n / d;
+        let q2 = match a.checked_mul(q1).and_then(|v| v.checked_add(q0)) {
+            Some(q2) if q2 <= limit => q2,
+            _ => {
+                //take the largest allowed semiconvergent (p0+k*p1)/(q0+k*q1)
+                let k = (limit - q0) / q1;
+                //with complete quotient z = n/d it beats p1/q1 iff z < 2k + q0/q1
+                let semi_wins = match (2 * k).checked_mul(d) {
+                    Some(kd) if kd <= n => cmp_mul_u128(n - kd, q1, q0, d) == Ordering::Less,
+                    _ => true,
+                };
+                if(semi_wins && k > 0){
+                    return Some((p0.checked_add(k.checked_mul(p1)?)?, q0 + k * q1));
+                }
+                return Some((p1, q1));
+            }
+        };
+        let p2 = a.checked_mul(p1)?.checked_add(p0)?;
+        (p0, q0, p1, q1) = (p1, q1, p2, q2);
+        (n, d) = (d, n - a * d);
+    }
+    Some((p1, q1))
+}
+#[cfg(feature = "container")]
+impl<T: Integer> TryFrom<Ordered<f64>> for Fraction<T> {
+    type Error = FromFloatError;
+    fn try_from(value: Ordered<f64>) -> Result<Self, Self::Error> {
+        Self::from_f64_exact(value.unbox())
+    }
+}
+impl<T: Integer> TryFrom<f64> for Fraction<T> {
+    type Error = FromFloatError;
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        Self::from_f64_exact(value)
+    }
+}
+
+///how to pick an integer for a value between two integers
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RoundingMode {
+    ///toward -∞
+    Floor,
+    ///toward +∞
+    Ceil,
+    ///toward zero
+    Trunc,
+    ///nearest, ties toward +∞
+    HalfUp,
+    ///nearest, ties toward -∞
+    HalfDown,
+    ///nearest, ties to the even neighbour (banker's rounding)
+    HalfEven,
+    ///nearest, ties away from zero
+    HalfAwayFromZero,
+    ///nearest, ties toward zero
+    HalfTowardZero,
+}
+
+impl<T: Integer> Fraction<T> {
+    ///(floor, numerator of the remainder over q), the remainder is in [0, q)
+    fn floor_parts(&self) -> (T, T) {
+        let mut int = self.p / self.q;
+        let mut r = self.p % self.q;
+        if(r.is_negative()){
+            int = int - T::ONE;
+            r = r + self.q;
+        }
+        (int, r)
+    }
+
+    pub fn floor(&self) -> Self {
+        Self::from_integer(self.floor_parts().0)
+    }
+
+    pub fn ceil(&self) -> Self {
+        let (int, r) = self.floor_parts();
+        Self::from_integer(if(r == T::ZERO){ int }else{ int + T::ONE })
+    }
+
+    pub fn trunc(&self) -> Self {
+        Self::from_integer(self.to_integer())
+    }
+
+    ///self - trunc(self), keeps the sign of self like `f64::fract`
+    pub fn fract(&self) -> Self {
+        Self { p: self.p % self.q, q: self.q }
+    }
+
+    pub fn round(&self, mode: RoundingMode) -> Self {
+        let (int, r) = self.floor_parts();
+        if(r == T::ZERO){
+            return Self::from_integer(int);
+        }
+        let up = match mode {
+            RoundingMode::Floor => false,
+            RoundingMode::Ceil => true,
+            RoundingMode::Trunc => int.is_negative(),
+            _ => {
+                //compare the remainder r/q with one half without forming 2r
+                match r.cmp(&(self.q - r)) {
+                    Ordering::Less => false,
+                    Ordering::Greater => true,
+                    Ordering::Equal => match mode {
+                        RoundingMode::HalfUp => true,
+                        RoundingMode::HalfDown => false,
+                        RoundingMode::HalfEven => int % (T::ONE + T::ONE) != T::ZERO,
+                        RoundingMode::HalfAwayFromZero => !int.is_negative(),
+                        _ => int.is_negative(),
+                    },
+                }
+            }
+        };
+        Self::from_integer(if(up){ int + T::ONE }else{ int })
+    }
+
+    ///nearest multiple of `step` under the rounding mode, e.g. snapping to 1/16.
+    ///`None` for a zero step or on overflow
+    pub fn checked_round_to_multiple(&self, step: &Self, mode: RoundingMode) -> Option<Self> {
+        self.checked_div(step)?.round(mode).checked_mul(step)
+    }
+
+    pub fn round_to_multiple(&self, step: &Self, mode: RoundingMode) -> Self {
+        if(step.is_zero()){
+            panic!("Cannot round to a multiple of zero.");
+        }
+        self.checked_round_to_multiple(step, mode).expect("attempt to round fraction with overflow")
+    }
+
+    ///the integer n with self = n*other + r and 0 <= r < |other|
+    pub fn checked_div_euclid(&self, other: &Self) -> Option<Self> {
+        let quotient = self.checked_div(other)?;
+        Some(if(other.p.is_negative()){ quotient.ceil() }else{ quotient.floor() })
+    }
+
+    ///the r in [0, |other|) with self = n*other + r
+    pub fn checked_rem_euclid(&self, other: &Self) -> Option<Self> {
+        let r = self.checked_rem(other)?;
+        if(r.p.is_negative()){ r.checked_add(&other.abs()) }else{ Some(r) }
+    }
+
+    pub fn div_euclid(&self, other: &Self) -> Self {
+        if other.p == T::ZERO {
+            panic!("Cannot divide by zero fraction.");
+        }
+        self.checked_div_euclid(other).expect("attempt to divide fractions with overflow")
+    }
+
+    pub fn rem_euclid(&self, other: &Self) -> Self {
+        if other.p == T::ZERO {
+            panic!("Cannot divide by zero fraction.");
+        }
+        self.checked_rem_euclid(other).expect("attempt to take remainder of fractions with overflow")
+    }
+}
+
+impl<T: Integer> Fraction<T> {
+    ///self^exp, a negative exponent inverts first. `None` on overflow or zero to a negative power
+    pub fn checked_pow(&self, exp: i32) -> Option<Self> {
+        let base = if(exp < 0){ self.checked_recip()? }else{ *self };
+        //p and q stay coprime, so no reduction is needed
+        Some(Self {
+            p: checked_pow(base.p, exp.unsigned_abs())?,
+            q: checked_pow(base.q, exp.unsigned_abs())?,
+        })
+    }
+
+    pub fn pow(&self, exp: i32) -> Self {
+        if(exp < 0 && self.is_zero()){
+            panic!("Cannot invert zero fraction.");
+        }
+        self.checked_pow(exp).expect("attempt to raise fraction to power with overflow")
+    }
+
+    ///the exact rational n-th root, `None` if it is irrational or does not exist (even root of a negative)
+    pub fn nth_root(&self, n: u32) -> Option<Self> {
+        if(n == 0){
+            return None;
+        }
+        //in lowest terms the root is rational only if both parts are perfect powers
+        let exact_root = |v: T| {
+            let r = iroot(v, n);
+            if(checked_pow(r, n)? == v){ Some(r) }else{ None }
+        };
+        let q = exact_root(self.q)?;
+        if(self.p.is_negative()){
+            if(n.is_multiple_of(2)){
+                return None;
+            }
+            //|p| may not exist (signed minimum), so search around the root of |p| - 1
+            let below = iroot(T::ZERO - (self.p + T::ONE), n);
+            for candidate in [below, below + T::ONE] {
+                let p = T::ZERO - candidate;
+                if(checked_pow(p, n) == Some(self.p)){
+                    return Some(Self { p, q });
+                }
+            }
+            return None;
+        }
+        Some(Self { p: exact_root(self.p)?, q })
+    }
+
+    pub fn checked_sqrt(&self) -> Option<Self> {
+        self.nth_root(2)
+    }
+
+    ///self^exp for a rational exponent, `Some` only when the result is exactly rational.
+    ///with exp = a/b in lowest terms that happens exactly when the b-th root is rational
+    pub fn pow_rational(&self, exp: &Fraction<i32>) -> Option<Self> {
+        let root = self.nth_root(exp.denominator().unsigned_abs())?;
+        root.checked_pow(exp.numerator())
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::interval::Interval;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_reduce_signed() {
+        let f = Fraction::new(6i8, -8i8);
+        assert_eq!((f.numerator(), f.denominator()), (-3, 4));
+        let f = Fraction::new(-10i128, -4i128);
+        assert_eq!((f.numerator(), f.denominator()), (5, 2));
+    }
+
+    #[test]
+    fn test_reduce_unsigned() {
+        let f = Fraction::new(12u32, 18u32);
+        assert_eq!((f.numerator(), f.denominator()), (2, 3));
+        let f = Fraction::new(0u64, 7u64);
+        assert_eq!((f.numerator(), f.denominator()), (0, 1));
+    }
+
+    #[test]
+    fn test_wide_range() {
+        let big = i128::MAX / 5;
+        let f = Fraction::new(big * 2, big * 4);
+        assert_eq!(f, Fraction::new(1, 2));
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_arithmetic_generic() {
+        let a = Fraction::new(1u16, 3u16);
+        let b = Fraction::new(1u16, 6u16);
+        assert_eq!(a.add(&b), Fraction::new(1, 2));
+        assert_eq!(a.subtract(&b), Fraction::new(1, 6));
+        assert_eq!(a.multiply(&b), Fraction::new(1, 18));
+        assert_eq!(a.divide(&b), Fraction::from(2));
+        assert_eq!(Fraction::new(7i64, 2).to_i64(), 3);
+        assert_eq!(Fraction::new(-7i32, 2).to_integer(), -3);
+    }
+
+    #[test]
+    #[should_panic(expected = "Denominator cannot be zero.")]
+    fn test_zero_denominator() {
+        Fraction::new(1u8, 0u8);
+    }
+
+    #[test]
+    fn test_operators() {
+        let a = Fraction::new(1i64, 2);
+        let b = Fraction::new(1i64, 3);
+        assert_eq!(a + b, Fraction::new(5, 6));
+        assert_eq!(a - b, Fraction::new(1, 6));
+        assert_eq!(a * b, Fraction::new(1, 6));
+        assert_eq!(a / b, Fraction::new(3, 2));
+        assert_eq!(-a, Fraction::new(-1, 2));
+        assert_eq!(Fraction::new(7i64, 2) % Fraction::new(1, 1), Fraction::new(1, 2));
+        assert_eq!(Fraction::new(-7i64, 2) % Fraction::new(2, 1), Fraction::new(-3, 2));
+        let (ra, rb) = (&a, &b);
+        assert_eq!(ra + rb, Fraction::new(5, 6));
+        let mut c = a;
+        c += b;
+        c *= Fraction::from(6);
+        c -= Fraction::from(1);
+        c /= Fraction::from(2);
+        assert_eq!(c, Fraction::from(2));
+        c %= Fraction::new(3, 4);
+        assert_eq!(c, Fraction::new(1, 2));
+    }
+
+    #[test]
+    fn test_sum_product() {
+        let parts = [Fraction::new(1u32, 2), Fraction::new(1, 3), Fraction::new(1, 6)];
+        assert_eq!(parts.iter().sum::<Fraction<u32>>(), Fraction::one());
+        assert_eq!(parts.into_iter().product::<Fraction<u32>>(), Fraction::new(1, 36));
+    }
+
+    #[test]
+    fn test_checked_cross_reduce() {
+        let big = i64::MAX / 2;
+        //naive big*big would overflow, cross reduction keeps it at 1
+        let a = Fraction::new(big, 3);
+        let b = Fraction::new(3, big);
+        assert_eq!(a.checked_mul(&b), Some(Fraction::one()));
+        assert_eq!(a.checked_div(&a), Some(Fraction::one()));
+        //b*d overflows, but the sum has a small denominator
+        let c = Fraction::new(1, big);
+        let d = Fraction::new(big - 1, big);
+        assert_eq!(c.checked_add(&d), Some(Fraction::one()));
+        assert_eq!(d.checked_sub(&d), Some(Fraction::zero()));
+    }
+
+    #[test]
+    fn test_checked_overflow() {
+        let a = Fraction::from_integer(i8::MAX);
+        assert_eq!(a.checked_add(&Fraction::one()), None);
+        assert_eq!(a.checked_mul(&Fraction::from(2)), None);
+        assert_eq!(Fraction::from_integer(i8::MIN).checked_neg(), None);
+        assert_eq!(Fraction::new(1u8, 2).checked_sub(&Fraction::one()), None);
+        assert_eq!(a.checked_div(&Fraction::zero()), None);
+        assert_eq!(a.checked_rem(&Fraction::zero()), None);
+    }
+
+    #[test]
+    fn test_checked_new_signed_min() {
+        //MIN % -1 and |MIN| both overflow inside the gcd
+        assert_eq!(Fraction::<i8>::checked_new(i8::MIN, -1), None);
+        assert_eq!(Fraction::<i8>::checked_new(i8::MIN, i8::MIN), Some(Fraction::one()));
+        assert_eq!(Fraction::<i8>::checked_new(-1, i8::MIN), None);
+        assert_eq!(Fraction::<i8>::checked_new(i8::MIN, 2), Some(Fraction::from_integer(-64)));
+        assert!(matches!("-128/-1".parse::<Fraction<i8>>(), Err(ParseFractionError::OutOfRange)));
+        assert_eq!("-128/-128".parse::<Fraction<i8>>().unwrap(), Fraction::one());
+        //0 and MIN have no gcd that fits either
+        assert_eq!(Fraction::<i8>::checked_new(0, i8::MIN), Some(Fraction::zero()));
+        assert_eq!(Fraction::new(0i8, i8::MIN), Fraction::zero());
+        assert_eq!("0/-128".parse::<Fraction<i8>>().unwrap(), Fraction::zero());
+        assert_eq!(checked_gcd(i64::MIN, i64::MIN), None);
+        assert_eq!(checked_gcd(0, i64::MIN), None);
+        assert_eq!(checked_gcd(i64::MIN, 6), Some(2));
+        assert_eq!(gcd_stein(i8::MIN, -1), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to take gcd with overflow")]
+    fn test_gcd_signed_min_overflow() {
+        gcd_stein(i64::MIN, i64::MIN);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add fractions with overflow")]
+    fn test_operator_overflow_panics() {
+        let _ = Fraction::from_integer(u8::MAX) + Fraction::one();
+    }
+
+    #[test]
+    fn test_order_by_value() {
+        assert!(Fraction::new(1i32, 3) < Fraction::new(1, 2));
+        assert!(Fraction::new(-1i32, 2) < Fraction::new(-1, 3));
+        assert!(Fraction::new(2u8, 3) > Fraction::new(3, 5));
+        let mut v = vec![Fraction::new(3i64, 4), Fraction::new(1, 3), Fraction::new(-2, 5), Fraction::new(1, 2)];
+        v.sort();
+        assert_eq!(v, [Fraction::new(-2, 5), Fraction::new(1, 3), Fraction::new(1, 2), Fraction::new(3, 4)]);
+    }
+
+    #[test]
+    fn test_order_no_overflow() {
+        let a = Fraction::new(i128::MAX - 1, i128::MAX);
+        let b = Fraction::new(i128::MAX - 2, i128::MAX - 1);
+        assert!(a > b);
+        assert!(-a < -b);
+        let c = Fraction::new(u128::MAX - 1, u128::MAX);
+        let d = Fraction::new(u128::MAX - 2, u128::MAX - 1);
+        assert!(c > d);
+        let e = Fraction::new(i64::MAX, i64::MAX - 1);
+        assert!(e > Fraction::one());
+        assert!(Fraction::from_integer(i64::MIN) < Fraction::new(i64::MIN + 1, 1));
+    }
+
+    #[test]
+    fn test_min_max_clamp() {
+        let a = Fraction::new(1i64, 3);
+        let b = Fraction::new(1i64, 2);
+        assert_eq!(a.max(b), b);
+        assert_eq!(a.min(b), a);
+        assert_eq!(Fraction::new(5i64, 2).clamp(a, b), b);
+        assert_eq!(Fraction::new(-5i64, 2).clamp(a, b), a);
+    }
+
+    #[test]
+    fn test_as_map_key_and_interval_endpoint() {
+        let mut map = BTreeMap::new();
+        map.insert(Fraction::new(1i64, 2), "half");
+        map.insert(Fraction::new(1i64, 3), "third");
+        map.insert(Fraction::new(2i64, 4), "half again");
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.values().copied().collect::<Vec<_>>(), ["third", "half again"]);
+
+        let itv = Interval::new(Fraction::new(1i64, 2), Fraction::new(1, 3), false, true);
+        assert_eq!(*itv.left(), Fraction::new(1, 3));
+        assert!(itv.contain(&Fraction::new(2, 5)));
+        assert!(!itv.contain(&Fraction::new(1, 3)));
+        assert!(itv.contain(&Fraction::new(1, 2)));
+    }
+
+    #[test]
+    fn test_parse_ratio() {
+        assert_eq!("3/4".parse::<Fraction<i64>>().unwrap(), Fraction::new(3, 4));
+        assert_eq!("-7/2".parse::<Fraction<i64>>().unwrap(), Fraction::new(-7, 2));
+        assert_eq!(" 6/-8 ".parse::<Fraction<i32>>().unwrap(), Fraction::new(-3, 4));
+        assert_eq!("42".parse::<Fraction<u8>>().unwrap(), Fraction::from(42));
+        assert_eq!("-128/1".parse::<Fraction<i16>>().unwrap(), Fraction::from(-128));
+    }
+
+    #[test]
+    fn test_parse_mixed() {
+        assert_eq!("1 1/2".parse::<Fraction<i64>>().unwrap(), Fraction::new(3, 2));
+        assert_eq!("-2  3/4".parse::<Fraction<i64>>().unwrap(), Fraction::new(-11, 4));
+        assert_eq!("3 0/5".parse::<Fraction<u32>>().unwrap(), Fraction::from(3));
+    }
+
+    #[test]
+    fn test_parse_decimal() {
+        assert_eq!("0.125".parse::<Fraction<i64>>().unwrap(), Fraction::new(1, 8));
+        assert_eq!("-.5".parse::<Fraction<i64>>().unwrap(), Fraction::new(-1, 2));
+        assert_eq!("2.".parse::<Fraction<i64>>().unwrap(), Fraction::from(2));
+        assert_eq!("1.5e-3".parse::<Fraction<i64>>().unwrap(), Fraction::new(3, 2000));
+        assert_eq!("2.5E+2".parse::<Fraction<i64>>().unwrap(), Fraction::from(250));
+        assert_eq!("0.50000000000000000000000".parse::<Fraction<i8>>().unwrap(), Fraction::new(1, 2));
+    }
+
+    #[test]
+    fn test_parse_repeating() {
+        assert_eq!("0.(3)".parse::<Fraction<i64>>().unwrap(), Fraction::new(1, 3));
+        assert_eq!("0.1(6)".parse::<Fraction<i64>>().unwrap(), Fraction::new(1, 6));
+        assert_eq!("-1.(142857)".parse::<Fraction<i64>>().unwrap(), Fraction::new(-8, 7));
+        assert_eq!("0.(9)".parse::<Fraction<i64>>().unwrap(), Fraction::one());
+        assert_eq!("0.(3)e1".parse::<Fraction<i64>>().unwrap(), Fraction::new(10, 3));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(matches!("".parse::<Fraction<i64>>(), Err(ParseFractionError::Empty)));
+        assert!(matches!("1/0".parse::<Fraction<i64>>(), Err(ParseFractionError::ZeroDenominator)));
+        assert!(matches!("1/2x".parse::<Fraction<i64>>(), Err(ParseFractionError::InvalidChar('x', 3))));
+        assert!(matches!("0.(3".parse::<Fraction<i64>>(), Err(ParseFractionError::UnexpectedEnd)));
+        assert!(matches!("1 1".parse::<Fraction<i64>>(), Err(ParseFractionError::UnexpectedEnd)));
+        assert!(matches!("/2".parse::<Fraction<i64>>(), Err(ParseFractionError::InvalidChar('/', 0))));
+        assert!(matches!("300".parse::<Fraction<u8>>(), Err(ParseFractionError::OutOfRange)));
+        assert!(matches!("-1/2".parse::<Fraction<u8>>(), Err(ParseFractionError::OutOfRange)));
+        assert!(matches!("1e".parse::<Fraction<i64>>(), Err(ParseFractionError::UnexpectedEnd)));
+    }
+
+    #[test]
+    fn test_display_flags() {
+        let f = Fraction::new(7i64, 2);
+        assert_eq!(format!("{f}"), "7/2");
+        assert_eq!(format!("{f:#}"), "3 1/2");
+        assert_eq!(format!("{:#}", -f), "-3 1/2");
+        assert_eq!(format!("{:#}", Fraction::new(-1i64, 2)), "-1/2");
+        assert_eq!(format!("{}", Fraction::from(4i64)), "4");
+        assert_eq!(format!("{:.3}", Fraction::new(2i64, 3)), "0.667");
+        assert_eq!(format!("{:.0}", Fraction::new(-5i64, 2)), "-3");
+        assert_eq!(format!("{:.2}", Fraction::new(-1i64, 1000)), "0.00");
+        assert_eq!(format!("{:.1}", Fraction::new(199i64, 20)), "10.0");
+        assert_eq!(format!("{:>6}", f), "   7/2");
+        assert_eq!(format!("{:+.2}", Fraction::new(-3i64, 2)), "-1.50");
+        assert_eq!(format!("{:+.2}", Fraction::new(3i64, 2)), "+1.50");
+        assert_eq!(format!("{:08.2}", Fraction::new(-3i64, 2)), "-0001.50");
+        assert_eq!(format!("{:+07.1}", Fraction::new(1i64, 4)), "+0000.3");
+        assert_eq!(format!("{:>7.1}", Fraction::new(-1i64, 4)), "   -0.3");
+    }
+
+    #[test]
+    fn test_display_styles() {
+        let f = Fraction::new(1i64, 6);
+        assert_eq!(f.display(FractionStyle::Repeating).to_string(), "0.1(6)");
+        assert_eq!(f.display(FractionStyle::Overline).to_string(), "0.16\u{0305}");
+        assert_eq!(Fraction::new(-22i64, 7).display(FractionStyle::Repeating).to_string(), "-3.(142857)");
+        assert_eq!(Fraction::new(3i64, 8).display(FractionStyle::Repeating).to_string(), "0.375");
+        assert_eq!(Fraction::from(5i64).display(FractionStyle::Repeating).to_string(), "5");
+        assert_eq!(Fraction::new(2i64, 3).display(FractionStyle::Truncated(4)).to_string(), "0.6666");
+        assert_eq!(Fraction::new(2i64, 3).display(FractionStyle::Rounded(4)).to_string(), "0.6667");
+        assert_eq!(Fraction::new(7i64, 2).display(FractionStyle::Unicode).to_string(), "⁷⁄₂");
+        assert_eq!(Fraction::new(-7i64, 12).display(FractionStyle::Unicode).to_string(), "-⁷⁄₁₂");
+        assert_eq!(Fraction::new(7i64, 2).display(FractionStyle::Latex).to_string(), "\\frac{7}{2}");
+        assert_eq!(Fraction::new(-1i64, 3).display(FractionStyle::Latex).to_string(), "-\\frac{1}{3}");
+    }
+
+    #[test]
+    fn test_display_extreme_values() {
+        let f = Fraction::new(1u8, 255);
+        assert_eq!(f.display(FractionStyle::Repeating).to_string(), "0.0(0392156862745098)");
+        let g = Fraction::new(i8::MIN, 3);
+        assert_eq!(format!("{g:#}"), "-42 2/3");
+        assert_eq!(Fraction::from_integer(i8::MIN).display(FractionStyle::Rounded(1)).to_string(), "-128.0");
+    }
+
+    #[test]
+    fn test_from_f64_exact() {
+        assert_eq!(Fraction::<i64>::from_f64_exact(0.375).unwrap(), Fraction::new(3, 8));
+        assert_eq!(Fraction::<i64>::from_f64_exact(-2.5).unwrap(), Fraction::new(-5, 2));
+        assert_eq!(Fraction::<i64>::from_f64_exact(0.0).unwrap(), Fraction::zero());
+        assert_eq!(Fraction::<i64>::from_f64_exact(0.1).unwrap(), Fraction::new(3602879701896397, 36028797018963968));
+        assert_eq!(Fraction::<i64>::try_from(1e18).unwrap(), Fraction::from(1_000_000_000_000_000_000));
+        assert!(matches!(Fraction::<i64>::from_f64_exact(f64::NAN), Err(FromFloatError::NotFinite)));
+        assert!(matches!(Fraction::<i64>::from_f64_exact(1e30), Err(FromFloatError::OutOfRange)));
+        assert!(matches!(Fraction::<i64>::from_f64_exact(1e-30), Err(FromFloatError::OutOfRange)));
+        assert!(matches!(Fraction::<i64>::from_f64_exact(1e-300), Err(FromFloatError::OutOfRange)));
+        assert!(matches!(Fraction::<i64>::from_f64_exact(f64::MIN_POSITIVE / 4.0), Err(FromFloatError::OutOfRange)));
+        assert!(matches!(Fraction::<u32>::from_f64_exact(-1.0), Err(FromFloatError::OutOfRange)));
+        assert_eq!(FromFloatError::NotFinite.to_string(), "cannot convert NaN or infinity to fraction");
+    }
+
+    #[test]
+    #[cfg(feature = "container")]
+    fn test_from_ordered_f64() {
+        let ordered = Ordered::new(0.75).unwrap();
+        assert_eq!(Fraction::<i32>::try_from(ordered).unwrap(), Fraction::new(3, 4));
+    }
+
+    #[test]
+    fn test_approximate_f64() {
+        let pi = std::f64::consts::PI;
+        assert_eq!(Fraction::approximate_f64(pi, 10i64).unwrap(), Fraction::new(22, 7));
+        assert_eq!(Fraction::approximate_f64(pi, 100i64).unwrap(), Fraction::new(311, 99));
+        assert_eq!(Fraction::approximate_f64(pi, 1000i64).unwrap(), Fraction::new(355, 113));
+        assert_eq!(Fraction::approximate_f64(-pi, 1000i64).unwrap(), Fraction::new(-355, 113));
+        assert_eq!(Fraction::approximate_f64(0.1, 1_000_000u32).unwrap(), Fraction::new(1, 10));
+        assert_eq!(Fraction::approximate_f64(0.333, 10i64).unwrap(), Fraction::new(1, 3));
+        assert_eq!(Fraction::approximate_f64(2.0, 1i64).unwrap(), Fraction::from(2));
+        assert_eq!(Fraction::approximate_f64(1e-40, 1000i64).unwrap(), Fraction::zero());
+        assert_eq!(Fraction::approximate_f64(1e-300, 1000i64).unwrap(), Fraction::zero());
+        assert_eq!(Fraction::approximate_f64(-f64::MIN_POSITIVE / 4.0, 1000i64).unwrap(), Fraction::zero());
+        //exactly halfway between 0/1 and 1/1 goes to the smaller denominator
+        assert_eq!(Fraction::approximate_f64(0.5, 1i64).unwrap(), Fraction::zero());
+        assert!(matches!(Fraction::approximate_f64(1e20, 10i64), Err(FromFloatError::OutOfRange)));
+    }
+
+    #[test]
+    fn test_floor_ceil_trunc_fract() {
+        let a = Fraction::new(7i64, 2);
+        let b = Fraction::new(-7i64, 2);
+        assert_eq!((a.floor(), a.ceil(), a.trunc()), (Fraction::from(3), Fraction::from(4), Fraction::from(3)));
+        assert_eq!((b.floor(), b.ceil(), b.trunc()), (Fraction::from(-4), Fraction::from(-3), Fraction::from(-3)));
+        assert_eq!(a.fract(), Fraction::new(1, 2));
+        assert_eq!(b.fract(), Fraction::new(-1, 2));
+        assert_eq!(Fraction::from(5i64).ceil(), Fraction::from(5));
+        assert_eq!(Fraction::new(i8::MIN, 3).floor(), Fraction::from(-43));
+    }
+
+    #[test]
+    fn test_round_modes() {
+        use RoundingMode::*;
+        let cases = [
+            (Fraction::new(5i64, 2), [2, 3, 2, 3, 2, 2, 3, 2]),
+            (Fraction::new(-5i64, 2), [-3, -2, -2, -2, -3, -2, -3, -2]),
+            (Fraction::new(7i64, 2), [3, 4, 3, 4, 3, 4, 4, 3]),
+            (Fraction::new(8i64, 3), [2, 3, 2, 3, 3, 3, 3, 3]),
+            (Fraction::new(-7i64, 3), [-3, -2, -2, -2, -2, -2, -2, -2]),
+        ];
+        let modes = [Floor, Ceil, Trunc, HalfUp, HalfDown, HalfEven, HalfAwayFromZero, HalfTowardZero];
+        for (value, expected) in cases {
+            for (mode, e) in modes.iter().zip(expected) {
+                assert_eq!(value.round(*mode), Fraction::from(e), "{value} {mode:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_round_to_multiple() {
+        let sixteenth = Fraction::new(1i64, 16);
+        assert_eq!(Fraction::new(1i64, 3).round_to_multiple(&sixteenth, RoundingMode::HalfEven), Fraction::new(5, 16));
+        assert_eq!(Fraction::new(-1i64, 3).round_to_multiple(&sixteenth, RoundingMode::Floor), Fraction::new(-3, 8));
+        assert_eq!(Fraction::new(33i64, 32).round_to_multiple(&Fraction::new(1, 16), RoundingMode::HalfEven), Fraction::one());
+        assert_eq!(Fraction::from(7i64).round_to_multiple(&Fraction::from(5), RoundingMode::HalfUp), Fraction::from(5));
+        assert_eq!(Fraction::one().checked_round_to_multiple(&Fraction::<i64>::zero(), RoundingMode::HalfUp), None);
+    }
+
+    #[test]
+    fn test_euclid() {
+        let a = Fraction::new(-7i64, 2);
+        let b = Fraction::new(3i64, 2);
+        assert_eq!(a.div_euclid(&b), Fraction::from(-3));
+        assert_eq!(a.rem_euclid(&b), Fraction::new(1, 1));
+        assert_eq!(a.div_euclid(&-b), Fraction::from(3));
+        assert_eq!(a.rem_euclid(&-b), Fraction::new(1, 1));
+        let c = Fraction::new(7i64, 2);
+        assert_eq!(c.div_euclid(&-b), Fraction::from(-2));
+        assert_eq!(c.rem_euclid(&-b), Fraction::new(1, 2));
+        assert_eq!(a.checked_div_euclid(&Fraction::zero()), None);
+    }
+
+    #[test]
+    fn test_pow() {
+        let a = Fraction::new(2i64, 3);
+        assert_eq!(a.pow(3), Fraction::new(8, 27));
+        assert_eq!(a.pow(-2), Fraction::new(9, 4));
+        assert_eq!(Fraction::new(-2i64, 3).pow(-3), Fraction::new(-27, 8));
+        assert_eq!(a.pow(0), Fraction::one());
+        assert_eq!(Fraction::<i64>::zero().checked_pow(-1), None);
+        assert_eq!(Fraction::new(1i8, 2).checked_pow(7), None);
+        assert_eq!(Fraction::new(1u8, 2).checked_pow(7), Some(Fraction::new(1, 128)));
+    }
+
+    #[test]
+    fn test_roots() {
+        assert_eq!(Fraction::new(9i64, 16).checked_sqrt(), Some(Fraction::new(3, 4)));
+        assert_eq!(Fraction::new(2i64, 1).checked_sqrt(), None);
+        assert_eq!(Fraction::new(-9i64, 16).checked_sqrt(), None);
+        assert_eq!(Fraction::new(-27i64, 8).nth_root(3), Some(Fraction::new(-3, 2)));
+        assert_eq!(Fraction::new(1i64, 1 << 60).nth_root(6), Some(Fraction::new(1, 1024)));
+        assert_eq!(Fraction::from(u64::MAX).checked_sqrt(), None);
+        assert_eq!(Fraction::from(4294967295u64 * 4294967295).checked_sqrt(), Some(Fraction::from(4294967295)));
+        assert_eq!(Fraction::from_integer(i8::MIN).nth_root(7), Some(Fraction::from(-2)));
+        assert_eq!(Fraction::from(0u32).nth_root(5), Some(Fraction::zero()));
+    }
+
+    #[test]
+    fn test_pow_rational() {
+        let x = Fraction::new(8i64, 27);
+        assert_eq!(x.pow_rational(&Fraction::new(2, 3)), Some(Fraction::new(4, 9)));
+        assert_eq!(x.pow_rational(&Fraction::new(-1, 3)), Some(Fraction::new(3, 2)));
+        assert_eq!(x.pow_rational(&Fraction::new(1, 2)), None);
+        assert_eq!(Fraction::new(16i64, 81).pow_rational(&Fraction::new(5, 4)), Some(Fraction::new(32, 243)));
+    }
+}
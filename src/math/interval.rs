@@ -1,4 +1,10 @@
 ﻿use std::fmt::{Display, Formatter};
+#[cfg(feature = "container")]
+use crate::containers::ord_wrap::Ordered;
+use crate::math::fraction::Fraction;
+#[cfg(feature = "container")]
+use crate::math::fraction::FromFloatError;
+use crate::math::traits::Integer;
 use std::ops::{Bound, Range, RangeBounds, RangeInclusive};
 
 pub struct Interval<T>where T:Ord{
@@ -121,3 +127,154 @@ impl<T: Ord> ClosedRange<T> {
         self.lf.clone()..=self.rt.clone()
     }
 }
+
+///simplest fraction in the positive range from `low` to `high` (`None` high is +∞).
+///the smallest integer that fits wins, otherwise both ends share the integer part n
+///and the answer is n + 1/(simplest in the reciprocal range of the fractional parts)
+fn simplest_positive<N: Integer>(low: Fraction<N>, low_open: bool, high: Option<Fraction<N>>, high_open: bool)
+                                 -> Option<Fraction<N>> {
+    let floor = low.floor();
+    let candidate = if(floor == low && !low_open){ floor }else{ floor.checked_add(&Fraction::one())? };
+    let fits = match high {
+        None => true,
+        Some(high) => candidate < high || (candidate == high && !high_open),
+    };
+    if(fits){
+        return Some(candidate);
+    }
+    let high = high?;
+    let low_part = low.checked_sub(&floor)?;
+    let high_part = high.checked_sub(&floor)?;
+    //x in (low, high)  <=>  1/(x - n) in (1/(high - n), 1/(low - n)), ends swap
+    let inner = simplest_positive(
+        high_part.checked_recip()?,
+        high_open,
+        if(low_part.is_zero()){ None }else{ Some(low_part.checked_recip()?) },
+        low_open,
+    )?;
+    floor.checked_add(&inner.checked_recip()?)
+}
+impl<N: Integer> Interval<Fraction<N>> {
+    ///the fraction in the interval with the smallest denominator, and among those the one
+    ///with the smallest numerator. `None` when the interval is empty or the answer overflows
+    pub fn simplest_rational(&self) -> Option<Fraction<N>> {
+        let (low, high) = (*self.left(), *self.right());
+        if(low == high && (self.left_open() || self.right_open())){
+            return None;
+        }
+        //integers have the smallest denominator, so the lowest one inside wins
+        let ceil = low.ceil();
+        let first = if(ceil == low && self.left_open()){ ceil.checked_add(&Fraction::one())? }else{ ceil };
+        if(first < high || (first == high && !self.right_open())){
+            return Some(first);
+        }
+        //no integer inside, the interval lies between two neighbouring ones and the answer is unique
+        if(high <= Fraction::zero()){
+            //mirror the negative side onto the positive one
+            let mirrored = simplest_positive(high.checked_neg()?, self.right_open(),
+                                             Some(low.checked_neg()?), self.left_open())?;
+            return mirrored.checked_neg();
+        }
+        simplest_positive(low, self.left_open(), Some(high), self.right_open())
+    }
+}
+///an endpoint as an exact fraction. floats past the range of i128 or closer to zero than 2^-126
+///have no exact i128 ratio, so they are moved inwards: a huge upper end to i128::MAX and tiny
+///ends to 0 or ±2^-126. an interval only reachable out there gives `None`, its answer would not fit N anyway
+#[cfg(feature = "container")]
+fn float_endpoint(value: f64, open: bool, is_low: bool) -> Option<(Fraction<i128>, bool)> {
+    match Fraction::<i128>::from_f64_exact(value) {
+        Ok(exact) => return Some((exact, open)),
+        Err(FromFloatError::NotFinite) => return None,
+        Err(FromFloatError::OutOfRange) => (),
+    }
+    if(value.abs() < 1.0){
+        let tiny = Fraction::new(1, 1i128 << 126);
+        let inwards = match (is_low, value > 0.0) {
+            (true, true) => tiny,
+            (false, false) => -tiny,
+            _ => Fraction::zero(),
+        };
+        return Some((inwards, false));
+    }
+    if(!is_low && value > 0.0){
+        return Some((Fraction::from_integer(i128::MAX), false));
+    }
+    None
+}
+#[cfg(feature = "container")]
+impl Interval<Ordered<f64>> {
+    ///simplest fraction inside the exact values of the float endpoints, see `float_endpoint` for
+    ///the ends that have no exact i128 ratio. `None` when the interval is empty or the answer does not fit in N
+    pub fn simplest_rational<N: Integer>(&self) -> Option<Fraction<N>> {
+        let (low, low_open) = float_endpoint(*self.left().as_ref(), self.left_open(), true)?;
+        let (high, high_open) = float_endpoint(*self.right().as_ref(), self.right_open(), false)?;
+        if(low > high){
+            return None;
+        }
+        let exact = Interval::new(low, high, low_open, high_open).simplest_rational()?;
+        Some(Fraction::new(N::from_i128(exact.numerator())?, N::from_i128(exact.denominator())?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn f(p: i64, q: i64) -> Fraction<i64> {
+        Fraction::new(p, q)
+    }
+    fn simplest(l: Fraction<i64>, r: Fraction<i64>, lo: bool, ro: bool) -> Option<Fraction<i64>> {
+        Interval::new(l, r, lo, ro).simplest_rational()
+    }
+
+    #[test]
+    fn test_simplest_fraction() {
+        assert_eq!(simplest(f(3, 10), f(4, 10), false, false), Some(f(1, 3)));
+        assert_eq!(simplest(f(1, 3), f(1, 2), true, true), Some(f(2, 5)));
+        assert_eq!(simplest(f(1, 3), f(1, 2), false, true), Some(f(1, 3)));
+        assert_eq!(simplest(f(1, 3), f(1, 2), true, false), Some(f(1, 2)));
+        assert_eq!(simplest(f(31415, 10000), f(31416, 10000), false, false), Some(f(333, 106)));
+        assert_eq!(simplest(f(5, 2), f(7, 2), false, false), Some(f(3, 1)));
+        assert_eq!(simplest(f(2, 1), f(3, 1), true, true), Some(f(5, 2)));
+        assert_eq!(simplest(f(-1, 2), f(1, 3), true, true), Some(f(0, 1)));
+        assert_eq!(simplest(f(-4, 10), f(-3, 10), false, false), Some(f(-1, 3)));
+        assert_eq!(simplest(f(-3, 1), f(-1, 1), false, false), Some(f(-3, 1)));
+        //straddling zero the lowest integer wins, not zero
+        assert_eq!(simplest(f(-5, 2), f(2, 1), false, false), Some(f(-2, 1)));
+        assert_eq!(simplest(f(-2, 1), f(2, 1), true, false), Some(f(-1, 1)));
+        assert_eq!(simplest(f(-1, 3), f(1, 2), false, false), Some(f(0, 1)));
+        assert_eq!(simplest(f(0, 1), f(1, 5), true, false), Some(f(1, 5)));
+        assert_eq!(simplest(f(1, 2), f(1, 2), false, false), Some(f(1, 2)));
+        assert_eq!(simplest(f(1, 2), f(1, 2), true, false), None);
+    }
+
+    #[test]
+    #[cfg(feature = "container")]
+    fn test_simplest_float() {
+        let itv = Interval::new(Ordered::new(2.71).unwrap(), Ordered::new(2.72).unwrap(), false, false);
+        assert_eq!(itv.simplest_rational::<i32>(), Some(Fraction::new(19, 7)));
+        let pi = std::f64::consts::PI;
+        let itv = Interval::new(Ordered::new(pi - 1e-6).unwrap(), Ordered::new(pi + 1e-6).unwrap(), true, true);
+        assert_eq!(itv.simplest_rational::<i64>(), Some(Fraction::new(355, 113)));
+        let itv = Interval::new(Ordered::new(0.0999).unwrap(), Ordered::new(0.1001).unwrap(), false, false);
+        assert_eq!(itv.simplest_rational::<u8>(), Some(Fraction::new(1, 10)));
+        //ends without an exact i128 ratio are moved inwards
+        let itv = Interval::new(Ordered::new(2.5).unwrap(), Ordered::new(1e300).unwrap(), false, false);
+        assert_eq!(itv.simplest_rational::<i32>(), Some(Fraction::from(3)));
+        let itv = Interval::new(Ordered::new(1e-40).unwrap(), Ordered::new(0.6).unwrap(), false, false);
+        assert_eq!(itv.simplest_rational::<i32>(), Some(Fraction::new(1, 2)));
+        let itv = Interval::new(Ordered::new(-1e-40).unwrap(), Ordered::new(0.6).unwrap(), false, false);
+        assert_eq!(itv.simplest_rational::<i32>(), Some(Fraction::zero()));
+        let itv = Interval::new(Ordered::new(-1e300).unwrap(), Ordered::new(1.0).unwrap(), false, false);
+        assert_eq!(itv.simplest_rational::<i64>(), None);
+        let itv = Interval::new(Ordered::new(1e-40).unwrap(), Ordered::new(2e-40).unwrap(), false, false);
+        assert_eq!(itv.simplest_rational::<i64>(), None);
+        let itv = Interval::new(Ordered::new(1e-300).unwrap(), Ordered::new(1e-299).unwrap(), false, false);
+        assert_eq!(itv.simplest_rational::<i64>(), None);
+        let itv = Interval::new(Ordered::new(-1e-300).unwrap(), Ordered::new(f64::MIN_POSITIVE / 4.0).unwrap(), false, false);
+        assert_eq!(itv.simplest_rational::<i64>(), Some(Fraction::zero()));
+        let itv = Interval::new(Ordered::new(1e-300).unwrap(), Ordered::new(0.4).unwrap(), false, false);
+        assert_eq!(itv.simplest_rational::<i64>(), Some(Fraction::new(1, 3)));
+    }
+}
@@ -1,9 +1,41 @@
-﻿pub const fn gcd_stein(mut a: i64, mut b: i64) -> i64 {
-    if a == 0 {
-        return b.abs();
+﻿use crate::math::traits::Integer;
+use std::cmp::Ordering;
+
+///panics when the gcd is |MIN| of a signed type, which only happens for 0 and MIN; see `checked_gcd`
+pub fn gcd_stein<T: Integer>(a: T, b: T) -> T {
+    checked_gcd(a, b).expect("attempt to take gcd with overflow")
+}
+///`None` when the gcd does not fit in T: both operands are 0 or the signed MIN, and not both 0
+pub fn checked_gcd<T: Integer>(mut a: T, mut b: T) -> Option<T> {
+    let checked_abs = |x: T| if T::SIGNED && x == T::MIN { None }else{ Some(x.abs()) };
+    if a == T::ZERO {
+        return checked_abs(b);
+    }
+    if b == T::ZERO {
+        return checked_abs(a);
+    }
+    //abs of the signed minimum overflows, so take one euclid step to shrink it first.
+    //MIN % -1 overflows too, but then the gcd is 1 anyway
+    if T::SIGNED && a == T::MIN {
+        if b == T::MIN {
+            return None;
+        }
+        a = match a.checked_rem(b) {
+            Some(r) => r,
+            None => return Some(T::ONE),
+        };
+        if a == T::ZERO {
+            return Some(b.abs());
+        }
     }
-    if b == 0 {
-        return a.abs();
+    if T::SIGNED && b == T::MIN {
+        b = match b.checked_rem(a) {
+            Some(r) => r,
+            None => return Some(T::ONE),
+        };
+        if b == T::ZERO {
+            return Some(a.abs());
+        }
     }
 
     let shift = (a | b).trailing_zeros();
@@ -11,15 +43,117 @@
     a = a.abs();
     b = b.abs();
 
-    a >>= a.trailing_zeros();
-    while b != 0 {
-        b >>= b.trailing_zeros();
+    a = a >> a.trailing_zeros();
+    while b != T::ZERO {
+        b = b >> b.trailing_zeros();
         if a > b {
             std::mem::swap(&mut a, &mut b);
         }
-        b -= a;
+        b = b - a;
+    }
+    Some(a << shift)
+}
+///floor of the square root, panics on negative input
+pub fn isqrt<T: Integer>(n: T) -> T {
+    if n.is_negative() {
+        panic!("Cannot take square root of negative number.");
+    }
+    if n < T::ONE + T::ONE {
+        return n;
+    }
+    let mut bits: u32 = 0;
+    let mut rest = n;
+    while rest != T::ZERO {
+        rest = rest >> 1;
+        bits += 1;
+    }
+    //start above the root and walk down with newton steps, x + n/x stays near 2*sqrt(n)
+    let mut x = T::ONE << bits.div_ceil(2);
+    loop {
+        let y = (x + n / x) >> 1;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+///base^exp by squaring, `None` on overflow
+pub fn checked_pow<T: Integer>(mut base: T, mut exp: u32) -> Option<T> {
+    let mut result = T::ONE;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result.checked_mul(base)?;
+        }
+        exp >>= 1;
+        if exp > 0 {
+            base = base.checked_mul(base)?;
+        }
+    }
+    Some(result)
+}
+///floor of the k-th root of a non-negative n
+pub fn iroot<T: Integer>(n: T, k: u32) -> T {
+    if n.is_negative() {
+        panic!("Cannot take root of negative number.");
+    }
+    if k == 0 {
+        panic!("Cannot take zeroth root.");
+    }
+    if k == 1 || n < T::ONE + T::ONE {
+        return n;
+    }
+    if k == 2 {
+        return isqrt(n);
+    }
+    let mut bits: u32 = 0;
+    let mut rest = n;
+    while rest != T::ZERO {
+        rest = rest >> 1;
+        bits += 1;
+    }
+    //the root has at most bits/k + 1 bits, binary search below that
+    let mut low = T::ONE;
+    let mut high = T::ONE << (bits / k + 1);
+    while high - low > T::ONE {
+        let mid = low + ((high - low) >> 1);
+        match checked_pow(mid, k) {
+            Some(v) if v <= n => low = mid,
+            _ => high = mid,
+        }
+    }
+    low
+}
+///full 256-bit product of two u128, returned as (high, low)
+pub const fn widening_mul_u128(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+    let (a_hi, a_lo) = (a >> 64, a & MASK);
+    let (b_hi, b_lo) = (b >> 64, b & MASK);
+    let lo_lo = a_lo * b_lo;
+    let hi_lo = a_hi * b_lo;
+    let lo_hi = a_lo * b_hi;
+    let hi_hi = a_hi * b_hi;
+    //middle column sums three values below 2^64, no overflow
+    let mid = (lo_lo >> 64) + (hi_lo & MASK) + (lo_hi & MASK);
+    let low = (mid << 64) | (lo_lo & MASK);
+    let high = hi_hi + (hi_lo >> 64) + (lo_hi >> 64) + (mid >> 64);
+    (high, low)
+}
+///compare a*b with c*d without overflowing
+pub fn cmp_mul_u128(a: u128, b: u128, c: u128, d: u128) -> Ordering {
+    widening_mul_u128(a, b).cmp(&widening_mul_u128(c, d))
+}
+///compare a*b with c*d without overflowing
+pub fn cmp_mul_i128(a: i128, b: i128, c: i128, d: i128) -> Ordering {
+    let left_negative = (a < 0) != (b < 0) && a != 0 && b != 0;
+    let right_negative = (c < 0) != (d < 0) && c != 0 && d != 0;
+    let left = widening_mul_u128(a.unsigned_abs(), b.unsigned_abs());
+    let right = widening_mul_u128(c.unsigned_abs(), d.unsigned_abs());
+    match (left_negative, right_negative) {
+        (false, false) => left.cmp(&right),
+        (true, true) => right.cmp(&left),
+        (false, true) => Ordering::Greater,
+        (true, false) => Ordering::Less,
     }
-    a << shift
 }
 #[inline(always)]
 pub fn get_bit<T>(value: T, index: usize) -> bool
@@ -0,0 +1,233 @@
+use crate::math::digits::DigitsError;
+
+///a number whose every place has its own base, lowest place first.
+///the place above the last base is unbounded, like the hours of h:m:s or the pounds of £sd.
+///this is not a `Digits`: those keep u8 places under one `max_digit`, while here every place
+///has its own base, bases may pass 255 and the top place has no bound at all
+pub struct MixedRadix {
+    bases: Vec<u32>,
+    //one more digit than bases
+    digits: Vec<u128>,
+}
+impl MixedRadix {
+    ///digits lowest first, one more than bases, each below the base of its place.
+    ///a base of 1 holds only 0, as the lowest place of the factorial system does
+    pub fn new(digits: Vec<u128>, bases: Vec<u32>) -> Result<MixedRadix, DigitsError> {
+        if(bases.contains(&0)){
+            return Err(DigitsError::UnsupportedBase(0));
+        }
+        if(digits.len() != bases.len() + 1){
+            return Err(DigitsError::PlaceCountMismatch(digits.len(), bases.len() + 1));
+        }
+        for (d, b) in digits.iter().zip(&bases) {
+            if(*d >= *b as u128){
+                return Err(DigitsError::PlaceExceedBase(*d, *b));
+            }
+        }
+        Ok(MixedRadix { bases, digits })
+    }
+    pub fn from_u128(mut value: u128, bases: &[u32]) -> MixedRadix {
+        if(bases.contains(&0)){
+            panic!("Base must be positive.");
+        }
+        let mut digits = Vec::with_capacity(bases.len() + 1);
+        for b in bases {
+            digits.push(value % *b as u128);
+            value /= *b as u128;
+        }
+        digits.push(value);
+        MixedRadix { bases: bases.to_vec(), digits }
+    }
+    ///`None` when the value does not fit in u128
+    pub fn to_u128(&self) -> Option<u128> {
+        let top = *self.digits.last()?;
+        self.digits.iter().zip(&self.bases).rev().try_fold(top, |acc, (d, b)| {
+            acc.checked_mul(*b as u128)?.checked_add(*d)
+        })
+    }
+    #[inline]
+    pub fn digits(&self) -> &[u128] {
+        &self.digits
+    }
+    #[inline]
+    pub fn bases(&self) -> &[u32] {
+        &self.bases
+    }
+
+    ///decimal places highest first, "1:02:03" for 3723 seconds in bases [60, 60].
+    ///bounded places are zero padded to the width of their largest digit
+    pub fn format(&self, separator: char) -> String {
+        let mut text = self.digits.last().map_or(String::new(), |d| d.to_string());
+        for (d, b) in self.digits.iter().zip(&self.bases).rev() {
+            let width = (*b - 1).to_string().len();
+            text.push(separator);
+            text.push_str(&format!("{d:0width$}"));
+        }
+        text
+    }
+    ///inverse of `format`, missing high places count as zero
+    pub fn parse(text: &str, separator: char, bases: &[u32]) -> Result<MixedRadix, DigitsError> {
+        if(text.is_empty()){
+            return Err(DigitsError::Empty);
+        }
+        let mut digits = Vec::with_capacity(bases.len() + 1);
+        for field in text.rsplit(separator) {
+            if(field.is_empty()){
+                return Err(DigitsError::Empty);
+            }
+            if let Some(c) = field.chars().find(|c| !c.is_ascii_digit()) {
+                return Err(DigitsError::NoConversionCharToNumRule(c));
+            }
+            digits.push(field.parse::<u128>().map_err(|_| DigitsError::Overflow)?);
+        }
+        if(digits.len() > bases.len() + 1){
+            return Err(DigitsError::PlaceCountMismatch(digits.len(), bases.len() + 1));
+        }
+        digits.resize(bases.len() + 1, 0);
+        MixedRadix::new(digits, bases.to_vec())
+    }
+}
+
+///[1, 2, ..., n]: the factorial number system, place i weighs i! and n places rank n items
+pub fn factorial_bases(n: usize) -> Vec<u32> {
+    (1..=n as u32).collect()
+}
+
+///lexicographic rank of a permutation of 0..n via its Lehmer code,
+///`None` when it is not such a permutation or the rank overflows
+pub fn permutation_rank(permutation: &[usize]) -> Option<u128> {
+    let n = permutation.len();
+    let mut seen = vec![false; n];
+    let mut digits = vec![0u128; n + 1];
+    for (i, p) in permutation.iter().enumerate() {
+        if(*p >= n || seen[*p]){
+            return None;
+        }
+        seen[*p] = true;
+        //smaller items still unused to the right
+        let smaller = seen[..*p].iter().filter(|s| !**s).count();
+        digits[n - 1 - i] = smaller as u128;
+    }
+    MixedRadix { bases: factorial_bases(n), digits }.to_u128()
+}
+
+///the permutation of 0..n with the given lexicographic rank, `None` for rank >= n!
+pub fn nth_permutation(n: usize, rank: u128) -> Option<Vec<usize>> {
+    let code = MixedRadix::from_u128(rank, &factorial_bases(n));
+    if(code.digits[n] != 0){
+        return None;
+    }
+    let mut unused: Vec<usize> = (0..n).collect();
+    Some(code.digits[..n].iter().rev().map(|d| unused.remove(*d as usize)).collect())
+}
+
+///bijective base-k digits of value, lowest first, each in 1..=k and none for zero
+pub fn to_bijective(mut value: u128, base: u32) -> Vec<u32> {
+    if(base == 0){
+        panic!("Base must be positive.");
+    }
+    let mut digits = Vec::new();
+    while value > 0 {
+        //like base k but a remainder of 0 borrows a full k
+        let d = (value - 1) % base as u128 + 1;
+        digits.push(d as u32);
+        value = (value - d) / base as u128;
+    }
+    digits
+}
+///inverse of `to_bijective`
+pub fn from_bijective(digits: &[u32], base: u32) -> Result<u128, DigitsError> {
+    digits.iter().rev().try_fold(0u128, |acc, d| {
+        if(*d == 0 || *d > base){
+            return Err(DigitsError::PlaceExceedBase(*d as u128, base));
+        }
+        acc.checked_mul(base as u128).and_then(|v| v.checked_add(*d as u128)).ok_or(DigitsError::Overflow)
+    })
+}
+
+///bijective text where alphabet[i] stands for the digit i + 1, empty for zero
+pub fn to_bijective_string(value: u128, alphabet: &[char]) -> String {
+    to_bijective(value, alphabet.len() as u32).iter().rev().map(|d| alphabet[*d as usize - 1]).collect()
+}
+pub fn from_bijective_string(text: &str, alphabet: &[char]) -> Result<u128, DigitsError> {
+    let mut digits = Vec::with_capacity(text.len());
+    for c in text.chars().rev() {
+        match alphabet.iter().position(|a| *a == c) {
+            Some(i) => digits.push(i as u32 + 1),
+            None => return Err(DigitsError::NoConversionCharToNumRule(c)),
+        }
+    }
+    from_bijective(&digits, alphabet.len() as u32)
+}
+
+const SPREADSHEET_ALPHABET: [char; 26] = [
+    'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M',
+    'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z',
+];
+///1 -> "A", 26 -> "Z", 27 -> "AA"
+pub fn spreadsheet_column(n: u128) -> String {
+    to_bijective_string(n, &SPREADSHEET_ALPHABET)
+}
+///"AB" -> 28, lowercase letters are accepted too
+pub fn parse_spreadsheet_column(text: &str) -> Result<u128, DigitsError> {
+    if(text.is_empty()){
+        return Err(DigitsError::Empty);
+    }
+    from_bijective_string(&text.to_ascii_uppercase(), &SPREADSHEET_ALPHABET)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_time_and_currency() {
+        let time = MixedRadix::from_u128(3723, &[60, 60]);
+        assert_eq!(time.digits(), [3, 2, 1]);
+        assert_eq!(time.format(':'), "1:02:03");
+        assert_eq!(MixedRadix::parse("1:02:03", ':', &[60, 60]).unwrap().to_u128(), Some(3723));
+        assert_eq!(MixedRadix::parse("02:03", ':', &[60, 60]).unwrap().to_u128(), Some(123));
+        assert!(MixedRadix::parse("1:60:00", ':', &[60, 60]).is_err());
+        assert!(MixedRadix::parse("1:2:3:4", ':', &[60, 60]).is_err());
+        assert!(MixedRadix::parse("1:x:3", ':', &[60, 60]).is_err());
+        assert!(matches!(MixedRadix::new(vec![0, 0], vec![0]), Err(DigitsError::UnsupportedBase(0))));
+        assert!(matches!(MixedRadix::parse("1:00", ':', &[0]), Err(DigitsError::UnsupportedBase(0))));
+        assert!(matches!(MixedRadix::parse("1::3", ':', &[60, 60]), Err(DigitsError::Empty)));
+        assert!(matches!(MixedRadix::parse("1:", ':', &[60, 60]), Err(DigitsError::Empty)));
+        assert!(matches!(MixedRadix::parse("340282366920938463463374607431768211456", ':', &[60]), Err(DigitsError::Overflow)));
+        //£1 2s 6d in pence with 12d to the shilling and 20s to the pound
+        let money = MixedRadix::new(vec![6, 2, 1], vec![12, 20]).unwrap();
+        assert_eq!(money.to_u128(), Some(270));
+        assert_eq!(money.format('.'), "1.02.06");
+        assert!(MixedRadix::new(vec![12, 0, 0], vec![12, 20]).is_err());
+    }
+
+    #[test]
+    fn test_permutations() {
+        assert_eq!(permutation_rank(&[0, 1, 2]), Some(0));
+        assert_eq!(permutation_rank(&[2, 1, 0]), Some(5));
+        assert_eq!(permutation_rank(&[1, 0, 2]), Some(2));
+        assert_eq!(permutation_rank(&[0, 0, 2]), None);
+        assert_eq!(nth_permutation(3, 3), Some(vec![1, 2, 0]));
+        assert_eq!(nth_permutation(3, 6), None);
+        for rank in 0..120 {
+            assert_eq!(permutation_rank(&nth_permutation(5, rank).unwrap()), Some(rank));
+        }
+    }
+
+    #[test]
+    fn test_bijective() {
+        assert_eq!(spreadsheet_column(1), "A");
+        assert_eq!(spreadsheet_column(26), "Z");
+        assert_eq!(spreadsheet_column(27), "AA");
+        assert_eq!(spreadsheet_column(702), "ZZ");
+        assert_eq!(spreadsheet_column(703), "AAA");
+        assert_eq!(spreadsheet_column(0), "");
+        assert_eq!(parse_spreadsheet_column("ab"), Ok(28));
+        assert_eq!(parse_spreadsheet_column("XFD"), Ok(16384));
+        assert!(parse_spreadsheet_column("A1").is_err());
+        assert_eq!(to_bijective(10, 10), [10]);
+        assert_eq!(from_bijective(&[10, 1], 10), Ok(20));
+        assert!(from_bijective(&[0], 10).is_err());
+    }
+}
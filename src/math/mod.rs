@@ -1,8 +1,17 @@
 ﻿pub mod fraction;
+pub mod bigint;
+pub mod decimal;
+pub mod continued_fraction;
+pub mod farey;
+pub mod expansion;
 pub mod interval;
 pub mod mathtool;
 pub mod traits;
 pub mod vector;
 pub mod complex;
 pub mod digits;
+pub mod alphabet;
+pub mod digit_format;
+pub mod checksum;
+pub mod mixed_radix;
 pub mod matrix;
\ No newline at end of file
@@ -1,4 +1,9 @@
-﻿
+﻿use crate::math::mathtool::{cmp_mul_i128, cmp_mul_u128};
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+use std::ops::{Add, BitOr, Div, Mul, Rem, Shl, Shr, Sub};
+
 pub trait NAN {
     fn is_nan(&self) -> bool;
 }
@@ -57,4 +62,76 @@ impl Sign{
 }
 pub trait Signed{
     fn sign(&self) -> Sign;
-}
\ No newline at end of file
+}
+/// primitive-like integer used as the numerator and denominator of a `Fraction`.
+/// implemented for every built-in integer; user types only need to fill in these methods.
+pub trait Integer:
+    Copy + Eq + Ord + Hash + Debug + Display
+    + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+    + Div<Output = Self> + Rem<Output = Self>
+    + BitOr<Output = Self> + Shl<u32, Output = Self> + Shr<u32, Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const MIN: Self;
+    const MAX: Self;
+    const SIGNED: bool;
+    fn trailing_zeros(self) -> u32;
+    fn is_negative(self) -> bool;
+    fn abs(self) -> Self;
+    fn checked_neg(self) -> Option<Self>;
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    fn checked_div(self, other: Self) -> Option<Self>;
+    fn checked_rem(self, other: Self) -> Option<Self>;
+    fn to_f64(self) -> f64;
+    fn to_i128(self) -> Option<i128>;
+    fn from_i128(value: i128) -> Option<Self>;
+    ///compare `a*b` with `c*d` exactly, the products must not overflow
+    fn cmp_mul(a: Self, b: Self, c: Self, d: Self) -> Ordering;
+}
+macro_rules! impl_integer {
+    ($signed:expr, $cmp_mul:ident, $wide:ty; $($t:ty),*) => {$(
+        impl Integer for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+            const MIN: Self = <$t>::MIN;
+            const MAX: Self = <$t>::MAX;
+            const SIGNED: bool = $signed;
+            #[inline]
+            fn trailing_zeros(self) -> u32 { <$t>::trailing_zeros(self) }
+            #[inline]
+            #[allow(unused_comparisons)]
+            fn is_negative(self) -> bool { self < 0 }
+            #[inline]
+            #[allow(unused_comparisons)]
+            fn abs(self) -> Self { if(self < 0){ Self::ZERO - self }else{ self } }
+            #[inline]
+            fn checked_neg(self) -> Option<Self> { <$t>::checked_neg(self) }
+            #[inline]
+            fn checked_add(self, other: Self) -> Option<Self> { <$t>::checked_add(self, other) }
+            #[inline]
+            fn checked_sub(self, other: Self) -> Option<Self> { <$t>::checked_sub(self, other) }
+            #[inline]
+            fn checked_mul(self, other: Self) -> Option<Self> { <$t>::checked_mul(self, other) }
+            #[inline]
+            fn checked_div(self, other: Self) -> Option<Self> { <$t>::checked_div(self, other) }
+            #[inline]
+            fn checked_rem(self, other: Self) -> Option<Self> { <$t>::checked_rem(self, other) }
+            #[inline]
+            fn to_f64(self) -> f64 { self as f64 }
+            #[inline]
+            fn to_i128(self) -> Option<i128> { i128::try_from(self).ok() }
+            #[inline]
+            fn from_i128(value: i128) -> Option<Self> { <$t>::try_from(value).ok() }
+            #[inline]
+            #[allow(clippy::unnecessary_cast)]
+            fn cmp_mul(a: Self, b: Self, c: Self, d: Self) -> Ordering {
+                $cmp_mul(a as $wide, b as $wide, c as $wide, d as $wide)
+            }
+        }
+    )*};
+}
+impl_integer!(true, cmp_mul_i128, i128; i8, i16, i32, i64, i128, isize);
+impl_integer!(false, cmp_mul_u128, u128; u8, u16, u32, u64, u128, usize);
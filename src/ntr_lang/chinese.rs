@@ -1,6 +1,9 @@
 use crate::dprintln;
+use crate::math::bigint::BigUint;
+use crate::math::decimal::Decimal;
 use crate::math::digits::Digits;
-use crate::ntr_lang::lang::LanguageParser;
+use crate::math::fraction::RoundingMode;
+use crate::ntr_lang::lang::{LanguageParser, TextError};
 
 pub struct Chinese {
     //prefer 零 over 〇
@@ -9,6 +12,16 @@ pub struct Chinese {
     prefer_one_ten:bool,
     //control whether to use traditional
     traditional:bool,
+    //use the anti-forgery capitals 壹贰叁 and 拾佰仟 of cheques and invoices
+    financial:bool,
+    //两 for a 2 before 百, 千 and the big units
+    liang:bool,
+    //廿, 卅 and 卌 for 20, 30 and 40, as in dates
+    nian_sa:bool,
+    //幺 for 1 when reading digit by digit
+    yao:bool,
+    //read every digit on its own with no units, as for years and phone numbers
+    digit_by_digit:bool,
 }
 ///units of 10^4 in simplified chinese
 const MEGA_UNITS: [&str; 12] = [
@@ -22,6 +35,57 @@ const MEGA_UNITS_TRAD: [&str; 12] = [
 const DIGITS: [char; 9] = [
     '一','二','三','四','五','六','七','八','九',
 ];
+///廿 卅 卌 for 20 30 40
+const TENS: [char; 3] = ['廿','卅','卌'];
+///financial digits in simplified chinese
+const FINANCIAL_DIGITS: [char; 9] = [
+    '壹','贰','叁','肆','伍','陆','柒','捌','玖',
+];
+///financial digits in traditional chinese
+const FINANCIAL_DIGITS_TRAD: [char; 9] = [
+    '壹','貳','參','肆','伍','陸','柒','捌','玖',
+];
+
+///value of a digit in any script: plain, financial, traditional or colloquial
+fn read_digit(c: char) -> Option<u8> {
+    Some(match c {
+        '零' | '〇' => 0,
+        '一' | '壹' | '弌' | '幺' => 1,
+        '二' | '贰' | '貳' | '弍' | '两' | '兩' => 2,
+        '三' | '叁' | '參' | '弎' => 3,
+        '四' | '肆' => 4,
+        '五' | '伍' => 5,
+        '六' | '陆' | '陸' => 6,
+        '七' | '柒' => 7,
+        '八' | '捌' => 8,
+        '九' | '玖' => 9,
+        _ => return None,
+    })
+}
+///power of ten of 十, 百 and 千
+fn read_small_unit(c: char) -> Option<u32> {
+    match c {
+        '十' | '拾' => Some(1),
+        '百' | '佰' => Some(2),
+        '千' | '仟' => Some(3),
+        _ => None,
+    }
+}
+///廿, 卅 and 卌 stand for two, three and four tens
+fn read_tens(c: char) -> Option<u32> {
+    match c {
+        '廿' => Some(2),
+        '卅' => Some(3),
+        '卌' => Some(4),
+        _ => None,
+    }
+}
+///power of ten of 万, 亿 and up, in either script
+fn read_big_unit(c: char) -> Option<u32> {
+    (1..MEGA_UNITS.len())
+        .find(|i| MEGA_UNITS[*i].starts_with(c) || MEGA_UNITS_TRAD[*i].starts_with(c))
+        .map(|i| i as u32 * 4)
+}
 
 impl Chinese {
     pub fn default()->Chinese{
@@ -29,15 +93,67 @@ impl Chinese {
             prefer_ling:true,
             prefer_one_ten:false,
             traditional:false,
+            financial:false,
+            liang:false,
+            nian_sa:false,
+            yao:false,
+            digit_by_digit:false,
         }
     }
     pub fn new(prefer_ling:bool, prefer_one_ten:bool, traditional:bool)->Chinese{
         Chinese{
             prefer_ling,
             prefer_one_ten,
-            traditional
+            traditional,
+            ..Chinese::default()
+        }
+    }
+    ///financial capitals, always with 零 and 壹拾
+    pub fn financial(traditional:bool)->Chinese{
+        Chinese{
+            prefer_ling:true,
+            prefer_one_ten:true,
+            traditional,
+            financial:true,
+            ..Chinese::default()
         }
     }
+    ///written style: 二百, 二万, no abbreviations
+    pub fn formal()->Chinese{
+        Chinese::default()
+    }
+    ///spoken style: 两百, 两万
+    pub fn colloquial()->Chinese{
+        Chinese{ liang:true, ..Chinese::default() }
+    }
+    ///days and months: 廿一, 卅 for values below 100, larger ones read as formal
+    pub fn date()->Chinese{
+        Chinese{ nian_sa:true, ..Chinese::default() }
+    }
+    ///phone and room numbers: digit by digit with 幺 for 1
+    pub fn phone()->Chinese{
+        Chinese{ yao:true, digit_by_digit:true, ..Chinese::default() }
+    }
+    pub fn with_traditional(mut self, traditional:bool)->Chinese{
+        self.traditional=traditional;
+        self
+    }
+    pub fn with_liang(mut self, liang:bool)->Chinese{
+        self.liang=liang;
+        self
+    }
+    pub fn with_nian_sa(mut self, nian_sa:bool)->Chinese{
+        self.nian_sa=nian_sa;
+        self
+    }
+    pub fn with_yao(mut self, yao:bool)->Chinese{
+        self.yao=yao;
+        self
+    }
+    pub fn with_digit_by_digit(mut self, digit_by_digit:bool)->Chinese{
+        self.digit_by_digit=digit_by_digit;
+        self
+    }
     pub fn megaunit(&self, place: usize) -> Result<&'static str, &str> {
         if (!self.traditional) {
             if place < MEGA_UNITS.len() {
@@ -52,11 +168,43 @@ impl Chinese {
     }
     pub fn digit_to_char(&self,digit: u8) -> char {
         if (digit > 0 && digit <= 9) {
-            DIGITS[digit as usize-1]
+            match (self.financial, self.traditional) {
+                (false, _) => DIGITS[digit as usize-1],
+                (true, false) => FINANCIAL_DIGITS[digit as usize-1],
+                (true, true) => FINANCIAL_DIGITS_TRAD[digit as usize-1],
+            }
         } else {
             self.zero()
         }
     }
+    ///every stored digit on its own, leading zeros included; 幺 for 1 when the style asks for it
+    pub fn digits_to_text(&self, num: &Digits) -> String {
+        let num = if(num.max_digit()==10){ num.clone() }else{ num.convert_base(10) };
+        if(num.len()==0){
+            return self.zero().to_string();
+        }
+        num.iter_from_highest().map(|d| if(d==1 && self.yao){ '幺' }else{ self.digit_to_char(d) }).collect()
+    }
+    ///n followed by a measure word, with 两 for exactly two: 两个, 十二个
+    pub fn count_to_text(&self, num: Digits, measure: &str) -> Result<String,&str> {
+        if(!self.financial && num.to_u64()==Some(2)){
+            let liang = if(self.traditional){ '兩' }else{ '两' };
+            return Ok(format!("{liang}{measure}"));
+        }
+        Ok(format!("{}{measure}",self.number_to_text(num)?))
+    }
+    ///两 in place of 二 before 百 and 千, and for a section of exactly 2 before 万 or 亿
+    fn use_liang(&self, section: &[u8], section_place: usize, section_start: usize) -> bool {
+        if(!self.liang || self.financial || section[section_place]!=2){
+            return false;
+        }
+        section_place>=2 || (section_place==0 && section_start>0 && section[1..].iter().all(|d| *d==0))
+    }
+    ///十, 百 or 千 for place 1 to 3 of a section
+    fn small_unit(&self, place: usize) -> char {
+        let units = if(self.financial){ ['拾','佰','仟'] }else{ ['十','百','千'] };
+        units[place-1]
+    }
     pub fn zero(&self) -> char {
         if(self.prefer_ling){
             '零'
@@ -64,6 +212,12 @@ impl Chinese {
             '〇'
         }
     }
+    fn minus(&self) -> char {
+        if(self.traditional){ '負' }else{ '负' }
+    }
+    fn point(&self) -> char {
+        if(self.traditional){ '點' }else{ '点' }
+    }
 }
 impl LanguageParser for Chinese{
     fn name() -> &'static str {
@@ -71,6 +225,14 @@ impl LanguageParser for Chinese{
     }
     fn number_to_text(&self, num: Digits) -> Result<String,&str> {
         dprintln!("num:{}",num.cast_to_string());
+        if(self.digit_by_digit){
+            return Ok(self.digits_to_text(&num));
+        }
+        //leading zeros would count as places, and other bases are read by value
+        let num = if(num.max_digit()==10){ num.trimmed() }else{ num.convert_base(10) };
+        if(num.len()==0){
+            return Ok(self.zero().to_string());
+        }
         let last_digit= num.len()-1;
         let mut text = String::new();
         let mut zeros:usize=0;
@@ -123,23 +285,29 @@ impl LanguageParser for Chinese{
                             match section_place{
                                 0=>(),
                                 1=>{
-                                    text.push('十');
+                                    //廿, 卅 and 卌 stand for both the digit and 十, only as a whole two-digit number
+                                    if(self.nian_sa && !self.financial && last_digit==1 && (2..=4).contains(section_digit)){
+                                        text.push(TENS[*section_digit as usize-2]);
+                                        continue;
+                                    }
+                                    text.push(self.small_unit(1));
                                     dprintln!("place: {place}; section_place:{section_place}; last digit: {last_digit}; section digit: {section_digit}; prefer yishi: {}",self.prefer_one_ten);
                                     if((*section_digit==1)&&(!self.prefer_one_ten)&&(section_start+section_place==last_digit)){
                                         continue;
                                     }
                                 },
-                                2=>{
-                                    text.push('百');
-                                },
-                                3=>{
-                                    text.push('千');
+                                2|3=>{
+                                    text.push(self.small_unit(section_place));
                                 },
                                 _=>{
                                     debug_assert!(false, "should not have section place of: {:?}",section_place );
                                 }
                             }
-                            text.push(self.digit_to_char(*section_digit));
+                            if(self.use_liang(&num.get_u8_array()[section_start..=place], section_place, section_start)){
+                                text.push(if(self.traditional){ '兩' }else{ '两' });
+                            }else{
+                                text.push(self.digit_to_char(*section_digit));
+                            }
                         }
                         dprintln!("{}",text);
                     }
@@ -149,6 +317,170 @@ impl LanguageParser for Chinese{
         dprintln!("{}\n\n",text);
         Ok(text.chars().rev().collect::<String>())
     }
+    fn signed_to_text(&self, negative: bool, num: Digits) -> Result<String,&str> {
+        let text = self.number_to_text(num)?;
+        //负零 is not a number anyone says
+        if(negative && text.chars().any(|c| c!=self.zero())){
+            Ok(format!("{}{text}",self.minus()))
+        }else{
+            Ok(text)
+        }
+    }
+    fn decimal_to_text(&self, n: &Decimal) -> Result<String,&str> {
+        let mut text = String::new();
+        if(n.is_negative()){
+            text.push(self.minus());
+        }
+        text.push_str(&self.number_to_text(n.integer_digits())?);
+        let fraction = n.fraction_digits();
+        if(fraction.len()>0){
+            text.push(self.point());
+            text.extend(fraction.iter_from_highest().map(|d| self.digit_to_char(d)));
+        }
+        Ok(text)
+    }
+    fn fraction_to_text(&self, negative: bool, numerator: Digits, denominator: Digits) -> Result<String,&str> {
+        if(denominator.iter().all(|d| d==0)){
+            return Err("Denominator cannot be zero");
+        }
+        let denominator = self.number_to_text(denominator)?;
+        let numerator = self.number_to_text(numerator)?;
+        let sign = if(negative){ self.minus().to_string() }else{ String::new() };
+        Ok(format!("{sign}{denominator}分之{numerator}"))
+    }
+    fn text_to_number(&self, text: &str) -> Result<Digits,TextError> {
+        let chars: Vec<char> = text.chars().collect();
+        if(chars.is_empty()){
+            return Err(TextError::new(0, None, "no number"));
+        }
+        let ten = BigUint::from(10u8);
+        //without any unit the digits are read one by one, like 二〇二六
+        let has_unit = chars.iter().any(|c| read_small_unit(*c).is_some() || read_tens(*c).is_some() || read_big_unit(*c).is_some());
+        if(!has_unit){
+            let mut digits = Vec::with_capacity(chars.len());
+            for (i, c) in chars.iter().enumerate().rev() {
+                digits.push(read_digit(*c).ok_or(TextError::new(i, Some(*c), "not part of a number"))?);
+            }
+            return Ok(Digits::from_u8_array(digits, 10).expect("digits are decimal"));
+        }
+        //sections below a big unit are added up in `section`, finished ones go into `result`
+        let mut result = BigUint::zero();
+        let mut result_unit: Option<u32> = None;
+        let mut section: u32 = 0;
+        let mut pending: Option<u8> = None;
+        let mut last_small: Option<u32> = None;
+        let mut last_big: Option<u32> = None;
+        //the unit just before a trailing digit, cleared by 零: 三万五 is 35000 but 三万零五 is 30005
+        let mut last_unit: Option<u32> = None;
+        for (i, c) in chars.iter().copied().enumerate() {
+            if let Some(d) = read_digit(c) {
+                if(pending.is_some()){
+                    return Err(TextError::new(i, Some(c), "digit without a unit before it"));
+                }
+                if(d == 0){
+                    last_unit = None;
+                }else{
+                    pending = Some(d);
+                }
+            }else if let Some(e) = read_small_unit(c) {
+                if(last_small.is_some_and(|l| e >= l)){
+                    return Err(TextError::new(i, Some(c), "unit out of order"));
+                }
+                //十 alone is 一十, and 百 or 千 alone are read the same way
+                section += pending.take().unwrap_or(1) as u32 * 10u32.pow(e);
+                last_small = Some(e);
+                last_unit = Some(e);
+            }else if let Some(tens) = read_tens(c) {
+                if(pending.is_some() || last_small.is_some_and(|l| l <= 1)){
+                    return Err(TextError::new(i, Some(c), "unit out of order"));
+                }
+                section += tens * 10;
+                last_small = Some(1);
+                last_unit = Some(1);
+            }else if let Some(e) = read_big_unit(c) {
+                section += pending.take().unwrap_or(0) as u32;
+                if(section == 0 && result == BigUint::zero()){
+                    //万 alone is 一万
+                    section = 1;
+                }
+                let power = ten.pow(e);
+                if(result_unit.is_none_or(|r| e > r)){
+                    //一万亿: the larger unit multiplies everything before it
+                    result = &(&result + &BigUint::from(section)) * &power;
+                    result_unit = Some(e);
+                }else if(last_big.is_some_and(|l| e >= l)){
+                    return Err(TextError::new(i, Some(c), "unit out of order"));
+                }else{
+                    result = &result + &(&BigUint::from(section) * &power);
+                }
+                section = 0;
+                last_small = None;
+                last_big = Some(e);
+                last_unit = Some(e);
+            }else{
+                return Err(TextError::new(i, Some(c), "not part of a number"));
+            }
+        }
+        result = &result + &BigUint::from(section);
+        if let Some(d) = pending {
+            //a digit right after a unit takes the place below it: 两千三 is 2300
+            let place = last_unit.map_or(0, |e| e - 1);
+            result = &result + &(&BigUint::from(d) * &ten.pow(place));
+        }
+        Ok(result.to_digits(10))
+    }
+    fn percent_to_text(&self, n: &Decimal) -> Result<String,&str> {
+        self.per(n, '百')
+    }
+    fn permille_to_text(&self, n: &Decimal) -> Result<String,&str> {
+        self.per(n, '千')
+    }
+}
+impl Chinese {
+    ///a money amount as written on cheques and invoices: 元 (圓 in traditional), 角 and 分,
+    ///ending in 整 (the older 正 means the same) when there are no 分. "1005.30" is 壹仟零伍元叁角整 in financial mode.
+    ///more than two decimal places is an error rather than silently rounding money
+    pub fn amount_to_text(&self, amount: &Decimal) -> Result<String,&str> {
+        let cents = amount.rescale(2, RoundingMode::Trunc);
+        if(&cents != amount){
+            return Err("Amount has more than two decimal places");
+        }
+        let fraction = cents.fraction_digits();
+        let (jiao, fen) = (fraction[1], fraction[0]);
+        let integer = cents.integer_digits();
+        let yuan = if(self.traditional){ '圓' }else{ '元' };
+        let mut text = String::new();
+        if(cents.is_negative()){
+            text.push(self.minus());
+        }
+        if(integer.len()>0){
+            text.push_str(&self.number_to_text(integer)?);
+            text.push(yuan);
+        }else if(jiao==0 && fen==0){
+            text.push(self.zero());
+            text.push(yuan);
+        }
+        if(jiao>0){
+            text.push(self.digit_to_char(jiao));
+            text.push('角');
+        }else if(fen>0 && !text.is_empty()){
+            //壹元零伍分: the empty 角 is spoken as 零
+            text.push(self.zero());
+        }
+        if(fen>0){
+            text.push(self.digit_to_char(fen));
+            text.push('分');
+        }else{
+            text.push('整');
+        }
+        Ok(text)
+    }
+    ///百分之n, 千分之n, with the sign in front
+    fn per(&self, n: &Decimal, unit: char) -> Result<String,&str> {
+        let value = self.decimal_to_text(&n.abs())?;
+        let sign = if(n.is_negative()){ self.minus().to_string() }else{ String::new() };
+        Ok(format!("{sign}{unit}分之{value}"))
+    }
 }
 #[cfg(test)]
 mod tests {
@@ -249,6 +581,7 @@ mod tests {
             prefer_ling: false,
             prefer_one_ten: false,
             traditional: true,
+            ..Chinese::default()
         };
         assert_eq!(
             trad.number_to_text(Digits::from_u64(10_0000_0000, 10)).unwrap(),
@@ -266,8 +599,140 @@ mod tests {
             prefer_ling: false,
             prefer_one_ten: false,
             traditional: false,
+            ..Chinese::default()
         };
         // 极大单位应返回 Err
         assert!(c.megaunit(999).is_err());
     }
+
+    #[test]
+    fn test_zero_and_negative() {
+        let c = Chinese::default();
+        assert_eq!(make(0), "零");
+        assert_eq!(c.number_to_text(Digits::new(10)).unwrap(), "零");
+        assert_eq!(c.number_to_text("0012".parse().unwrap()).unwrap(), "十二");
+        assert_eq!(c.number_to_text(Digits::from_u64(255, 16)).unwrap(), "二百五十五");
+        assert_eq!(c.signed_to_text(true, Digits::from_u64(15, 10)).unwrap(), "负十五");
+        assert_eq!(c.signed_to_text(true, Digits::from_u64(0, 10)).unwrap(), "零");
+        assert_eq!(Chinese::new(true, false, true).signed_to_text(true, Digits::from_u64(2, 10)).unwrap(), "負二");
+    }
+
+    #[test]
+    fn test_decimal_fraction_percent() {
+        let c = Chinese::default();
+        let d = |s: &str| s.parse::<Decimal>().unwrap();
+        assert_eq!(c.decimal_to_text(&d("3.1415")).unwrap(), "三点一四一五");
+        assert_eq!(c.decimal_to_text(&d("-0.05")).unwrap(), "负零点零五");
+        assert_eq!(c.decimal_to_text(&d("10.50")).unwrap(), "十点五零");
+        assert_eq!(c.decimal_to_text(&d("12")).unwrap(), "十二");
+        assert_eq!(c.fraction_to_text(false, Digits::from_u64(2, 10), Digits::from_u64(3, 10)).unwrap(), "三分之二");
+        assert_eq!(c.fraction_to_text(true, Digits::from_u64(1, 10), Digits::from_u64(100, 10)).unwrap(), "负一百分之一");
+        assert!(c.fraction_to_text(false, Digits::from_u64(1, 10), Digits::from_u64(0, 10)).is_err());
+        assert_eq!(c.percent_to_text(&d("50")).unwrap(), "百分之五十");
+        assert_eq!(c.percent_to_text(&d("3.5")).unwrap(), "百分之三点五");
+        assert_eq!(c.percent_to_text(&d("-2")).unwrap(), "负百分之二");
+        assert_eq!(c.permille_to_text(&d("3")).unwrap(), "千分之三");
+    }
+
+    #[test]
+    fn test_parse() {
+        let c = Chinese::default();
+        let parse = |s: &str| c.text_to_number(s).unwrap().to_u64().unwrap();
+        assert_eq!(parse("一万零五"), 10005);
+        assert_eq!(parse("十一"), 11);
+        assert_eq!(parse("一十一"), 11);
+        assert_eq!(parse("两千三"), 2300);
+        assert_eq!(parse("三万五"), 35000);
+        assert_eq!(parse("一百一"), 110);
+        assert_eq!(parse("一亿零一百万"), 1_0100_0000);
+        assert_eq!(parse("一万亿"), 1_0000_0000_0000);
+        assert_eq!(parse("二〇二六"), 2026);
+        assert_eq!(parse("廿一"), 21);
+        assert_eq!(parse("卅"), 30);
+        assert_eq!(parse("十億零三萬"), 10_0003_0000);
+        assert_eq!(parse("壹仟贰佰叁拾肆"), 1234);
+        assert_eq!(parse("零"), 0);
+        assert_eq!(c.text_to_number("〇〇七").unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let c = Chinese::default();
+        let error = |s: &str| c.text_to_number(s).err().map(|e| (e.index(), e.found()));
+        assert_eq!(error("一二百"), Some((1, Some('二'))));
+        assert_eq!(error("三百四千"), Some((3, Some('千'))));
+        assert_eq!(error("五块"), Some((1, Some('块'))));
+        assert_eq!(error("一万二万"), Some((3, Some('万'))));
+        assert_eq!(error(""), Some((0, None)));
+        assert_eq!(format!("{:?}", c.text_to_number("负五").err().unwrap()), "not part of a number at char 0 '负'");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let c = Chinese::default();
+        for n in [1u64, 10, 11, 101, 110, 1001, 10005, 10050, 1005000, 1_0100_0000, 1_1451_4191_9810, 1234_5678_9012_3456_7890] {
+            let text = make(n);
+            assert_eq!(c.text_to_number(&text).unwrap(), Digits::from_u64(n, 10), "{text}");
+        }
+        let trad = Chinese::new(false, true, true);
+        let n = Digits::from_u64(10_0003_0000, 10);
+        assert_eq!(trad.text_to_number(&trad.number_to_text(n.clone()).unwrap()).unwrap(), n);
+    }
+
+    #[test]
+    fn test_financial() {
+        let c = Chinese::financial(false);
+        let amount = |s: &str| c.amount_to_text(&s.parse().unwrap()).unwrap();
+        assert_eq!(amount("1005.30"), "壹仟零伍元叁角整");
+        assert_eq!(amount("1005.3"), "壹仟零伍元叁角整");
+        assert_eq!(amount("10"), "壹拾元整");
+        assert_eq!(amount("1.05"), "壹元零伍分");
+        assert_eq!(amount("1.25"), "壹元贰角伍分");
+        assert_eq!(amount("0.50"), "伍角整");
+        assert_eq!(amount("0.07"), "柒分");
+        assert_eq!(amount("0"), "零元整");
+        assert_eq!(amount("-3"), "负叁元整");
+        assert_eq!(amount("100000010.00"), "壹亿零壹拾元整");
+        assert!(c.amount_to_text(&"1.005".parse().unwrap()).is_err());
+        let trad = Chinese::financial(true);
+        assert_eq!(trad.amount_to_text(&"23000.2".parse().unwrap()).unwrap(), "貳萬參仟圓貳角整");
+        //financial text reads back
+        let n = Digits::from_u64(1_0203_0405, 10);
+        assert_eq!(c.text_to_number(&c.number_to_text(n.clone()).unwrap()).unwrap(), n);
+    }
+
+    #[test]
+    fn test_styles() {
+        let text = |c: &Chinese, n: u64| c.number_to_text(Digits::from_u64(n, 10)).unwrap();
+        let colloquial = Chinese::colloquial();
+        assert_eq!(text(&colloquial, 2000), "两千");
+        assert_eq!(text(&colloquial, 1200), "一千两百");
+        assert_eq!(text(&colloquial, 2_0000), "两万");
+        assert_eq!(text(&colloquial, 2_0000_0000), "两亿");
+        assert_eq!(text(&colloquial, 22_0000), "二十二万");
+        assert_eq!(text(&colloquial, 2), "二");
+        assert_eq!(text(&colloquial, 12), "十二");
+        assert_eq!(text(&Chinese::formal(), 2000), "二千");
+        assert_eq!(colloquial.count_to_text(Digits::from_u64(2, 10), "个").unwrap(), "两个");
+        assert_eq!(colloquial.count_to_text(Digits::from_u64(12, 10), "个").unwrap(), "十二个");
+        let date = Chinese::date();
+        assert_eq!(text(&date, 21), "廿一");
+        assert_eq!(text(&date, 30), "卅");
+        assert_eq!(text(&date, 40), "卌");
+        assert_eq!(text(&date, 15), "十五");
+        assert_eq!(text(&date, 2026), "二千零二十六");
+        assert_eq!(text(&date, 121), "一百二十一");
+        assert_eq!(text(&date, 105), "一百零五");
+        assert_eq!(date.digits_to_text(&Digits::from_u64(2026, 10)), "二零二六");
+        let phone = Chinese::phone();
+        assert_eq!(phone.number_to_text("01012345".parse().unwrap()).unwrap(), "零幺零幺二三四五");
+        assert_eq!(Chinese::formal().with_traditional(true).with_liang(true).number_to_text(Digits::from_u64(200, 10)).unwrap(), "兩百");
+        //every style reads back through the same parser
+        for c in [Chinese::formal(), colloquial, date, phone] {
+            for n in [2u64, 21, 30, 121, 200, 2026, 2_0000, 1_2000_0000, 13800138000] {
+                let digits = Digits::from_u64(n, 10);
+                assert_eq!(c.text_to_number(&c.number_to_text(digits.clone()).unwrap()).unwrap(), digits);
+            }
+        }
+    }
 }
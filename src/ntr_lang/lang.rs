@@ -1,6 +1,105 @@
+use std::fmt::{Debug, Display, Formatter};
+use crate::math::decimal::Decimal;
 use crate::math::digits::Digits;
 
+///where reading a number out of text went wrong: the char index and the char there,
+///`None` when the text ended too early
+pub struct TextError {
+    index: usize,
+    found: Option<char>,
+    reason: &'static str,
+}
+impl TextError {
+    pub fn new(index: usize, found: Option<char>, reason: &'static str) -> TextError {
+        TextError { index, found, reason }
+    }
+    pub fn index(&self) -> usize {
+        self.index
+    }
+    pub fn found(&self) -> Option<char> {
+        self.found
+    }
+    pub fn reason(&self) -> &'static str {
+        self.reason
+    }
+}
+impl Debug for TextError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.found {
+            Some(c) => write!(f, "{} at char {} '{c}'", self.reason, self.index),
+            None => write!(f, "{} at the end (char {})", self.reason, self.index),
+        }
+    }
+}
+impl Display for TextError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+impl std::error::Error for TextError {}
+
 pub trait LanguageParser {
     fn name() -> &'static str;
     fn number_to_text(&self, n: Digits) -> Result<String,&str>;
-}
\ No newline at end of file
+    ///n, or minus n when negative. the default only reads non-negative numbers
+    fn signed_to_text(&self, negative: bool, n: Digits) -> Result<String,&str> {
+        if(negative && n.iter().any(|d| d!=0)){
+            return Err("Negative numbers are not supported");
+        }
+        self.number_to_text(n)
+    }
+    ///digits after the point are read one by one, as many as the scale.
+    ///the default only reads whole numbers
+    fn decimal_to_text(&self, n: &Decimal) -> Result<String,&str> {
+        if(n.scale()>0){
+            return Err("Decimals are not supported");
+        }
+        self.signed_to_text(n.is_negative(), n.integer_digits())
+    }
+    ///numerator over denominator, as given without reducing; an error for a zero denominator
+    fn fraction_to_text(&self, _negative: bool, _numerator: Digits, _denominator: Digits) -> Result<String,&str> {
+        Err("Fractions are not supported")
+    }
+    ///n per hundred
+    fn percent_to_text(&self, _n: &Decimal) -> Result<String,&str> {
+        Err("Percentages are not supported")
+    }
+    ///n per thousand
+    fn permille_to_text(&self, _n: &Decimal) -> Result<String,&str> {
+        Err("Permille is not supported")
+    }
+    ///the inverse of `number_to_text`. the default reads nothing
+    fn text_to_number(&self, text: &str) -> Result<Digits,TextError> {
+        Err(TextError::new(0, text.chars().next(), "reading numbers is not supported"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    ///a language that only knows number_to_text, as a downstream crate might write it
+    struct Plain;
+    impl LanguageParser for Plain {
+        fn name() -> &'static str {
+            "plain"
+        }
+        fn number_to_text(&self, n: Digits) -> Result<String,&str> {
+            Ok(ToString::to_string(&n))
+        }
+    }
+
+    #[test]
+    fn test_defaults() {
+        assert_eq!(Plain.signed_to_text(false, Digits::from_u64(12, 10)).unwrap(), "12");
+        assert_eq!(Plain.signed_to_text(true, Digits::new(10)).unwrap(), "0");
+        assert!(Plain.signed_to_text(true, Digits::from_u64(12, 10)).is_err());
+        assert_eq!(Plain.decimal_to_text(&"7".parse().unwrap()).unwrap(), "7");
+        assert!(Plain.decimal_to_text(&"7.5".parse().unwrap()).is_err());
+        assert!(Plain.fraction_to_text(false, Digits::from_u64(1, 10), Digits::from_u64(2, 10)).is_err());
+        assert!(Plain.percent_to_text(&"5".parse().unwrap()).is_err());
+        let error = Plain.text_to_number("12").unwrap_err();
+        assert_eq!((error.index(), error.found()), (0, Some('1')));
+        assert_eq!(error.to_string(), "reading numbers is not supported at char 0 '1'");
+    }
+}